@@ -0,0 +1,29 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::{CathodeRayTube, GRID_SIZE};
+
+/// Program running exactly as many cycles as the screen has pixels, keeping
+/// the sprite on the screen. The size is ignored, the screen is fixed.
+impl Generate for CathodeRayTube {
+    const DEFAULT_SIZE: usize = GRID_SIZE;
+
+    fn generate(rng: &mut Rng, _size: usize) -> String {
+        let mut input = String::new();
+        let mut cycles = 0;
+        let mut x: i64 = 1;
+
+        while cycles < GRID_SIZE {
+            if cycles + 2 <= GRID_SIZE && rng.chance(0.6) {
+                let value = rng.between(-x..=39 - x);
+                x += value;
+                cycles += 2;
+                input.push_str(&format!("addx {}\n", value));
+            } else {
+                cycles += 1;
+                input.push_str("noop\n");
+            }
+        }
+
+        return input;
+    }
+}
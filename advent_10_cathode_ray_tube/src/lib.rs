@@ -0,0 +1,131 @@
+mod generate;
+
+use aoc_core::parse::{self, numbered_lines};
+use aoc_core::{verbose, ParseError, Puzzle, Result};
+
+const DRAWN: char = '#';
+const EMPTY: char = '.';
+const GRID_SIZE: usize = 240;
+
+pub enum Instruction {
+    Noop,
+    AddX(i32),
+}
+
+pub struct CathodeRayTube;
+
+impl Puzzle for CathodeRayTube {
+    const DAY: u8 = 10;
+
+    type Parsed = Vec<Instruction>;
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(instructions: &Self::Parsed) -> Result<Self::Answer1> {
+        part1(instructions)
+    }
+
+    fn part2(instructions: &Self::Parsed) -> Result<Self::Answer2> {
+        part2(instructions)
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Instruction>> {
+    let mut instructions = Vec::new();
+
+    for (line_number, line) in numbered_lines(input) {
+        let (command, argument) = line.split_once(' ').unwrap_or((line, ""));
+
+        match command {
+            "addx" if argument.is_empty() => return Err(Box::new(ParseError::end_of_line(line_number, line, "an addx value"))),
+            "addx" => instructions.push(Instruction::AddX(parse::number(line_number, line, argument)?)),
+            "noop" if argument.is_empty() => instructions.push(Instruction::Noop),
+            "noop" => return Err(Box::new(ParseError::new(line_number, line, argument, "end of line after 'noop'"))),
+            _ => return Err(Box::new(ParseError::new(line_number, line, command, "a CPU command 'addx' or 'noop'"))),
+        }
+    }
+
+    return Ok(instructions);
+}
+
+fn part1(instructions: &[Instruction]) -> Result<i32> {
+    let (signal_strength_sum, _) = run_program(instructions);
+    return Ok(signal_strength_sum);
+}
+
+fn part2(instructions: &[Instruction]) -> Result<String> {
+    let (_, crt_image) = run_program(instructions);
+    return Ok(render_image(&crt_image));
+}
+
+fn run_program(instructions: &[Instruction]) -> (i32, Vec<char>) {
+    let mut cycle: i32 = 0;
+    let mut x: i32 = 1;
+    let mut signal_strength_sum: i32 = 0;
+    let mut crt_image: Vec<char> = vec![' '; GRID_SIZE];
+    for instruction in instructions {
+        cycle += 1;
+
+        signal_strength_sum = get_signal_strength (cycle, x, signal_strength_sum);
+        crt_image = draw_pixel(cycle, x, crt_image);
+
+        if let Instruction::AddX(add_v) = instruction {
+            cycle += 1;
+            signal_strength_sum = get_signal_strength (cycle, x, signal_strength_sum);
+            crt_image = draw_pixel(cycle, x, crt_image);
+
+            x += add_v;
+        }
+    }
+
+    verbose!("Total cycles run: {}", cycle);
+    verbose!("X value: {}", x);
+
+    return (signal_strength_sum, crt_image);
+}
+
+fn draw_pixel(cycle: i32, x: i32, mut crt_image: Vec<char>) -> Vec<char> {
+    let drawing_index = match cycle {
+        _ if cycle > 40 && cycle <= 80 => cycle - 40 - 1,
+        _ if cycle > 80 && cycle <= 120 => cycle - 80 - 1,
+        _ if cycle > 120 && cycle <= 160 => cycle - 120 - 1,
+        _ if cycle > 160 && cycle <= 200 => cycle - 160 - 1,
+        _ if cycle > 200 => cycle - 200 - 1,
+        _ => cycle - 1
+    } ;
+
+    let index: usize  = cycle as usize - 1;
+    if x - 1 == drawing_index || x == drawing_index || x + 1 == drawing_index {
+        crt_image[index] = DRAWN;
+    }
+    else {
+        crt_image[index] = EMPTY;
+    }
+
+    return crt_image;
+}
+
+fn get_signal_strength (cycle: i32, x: i32, signal_strength_sum: i32) -> i32 {
+    let signal_strength = match cycle {
+        20 => cycle * x,
+        60 => cycle * x,
+        100 => cycle * x,
+        140 => cycle * x,
+        180 => cycle * x,
+        220 => cycle * x,
+        _ => 0
+    };
+
+    return signal_strength_sum + signal_strength;
+}
+
+fn render_image (crt_image: &[char]) -> String {
+    let chunks = crt_image.chunks(40);
+
+    let rows: Vec<String> = chunks.map(|chunk| chunk.iter().collect()).collect();
+    return rows.join("\n");
+}
\ No newline at end of file
@@ -0,0 +1,99 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::MonkeyInTheMiddle;
+
+const DIVISORS: [i64; 8] = [2, 3, 5, 7, 11, 13, 17, 19];
+
+/// Rounds of part 1, where worry levels are only divided by three and can
+/// outgrow 64 bits.
+const PART1_ROUNDS: usize = 20;
+
+/// Draws before giving up on multiplying monkeys, which few monkeys passing
+/// items around in a short loop can never avoid overflowing with.
+const ATTEMPTS: usize = 100;
+
+struct Plan {
+    items: Vec<i64>,
+    /// `None` multiplies the worry level by itself.
+    operation: (char, Option<i64>),
+    divisor: i64,
+    targets: [usize; 2],
+}
+
+/// `size` monkeys (two to eight) with distinct prime tests, never throwing
+/// to themselves. At most one monkey squares, as in the real input, and monkeys
+/// whose part 1 worry levels would overflow are drawn again.
+impl Generate for MonkeyInTheMiddle {
+    const DEFAULT_SIZE: usize = 8;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let count = size.clamp(2, DIVISORS.len());
+        let mut attempt = 0;
+        let monkeys = loop {
+            let monkeys = plan(rng, count, attempt < ATTEMPTS);
+            if !overflows(&monkeys) {
+                break monkeys;
+            }
+            attempt += 1;
+        };
+
+        let monkeys: Vec<String> = monkeys.iter().enumerate().map(|(monkey, plan)| {
+            let items: Vec<String> = plan.items.iter().map(|item| item.to_string()).collect();
+            let operand = match plan.operation.1 {
+                Some(value) => value.to_string(),
+                None => String::from("old"),
+            };
+
+            format!(
+                "Monkey {}:\n  Starting items: {}\n  Operation: new = old {} {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                monkey, items.join(", "), plan.operation.0, operand, plan.divisor, plan.targets[0], plan.targets[1]
+            )
+        }).collect();
+
+        return monkeys.join("\n");
+    }
+}
+
+fn plan(rng: &mut Rng, count: usize, multiply: bool) -> Vec<Plan> {
+    let mut divisors = DIVISORS;
+    rng.shuffle(&mut divisors);
+    let squaring = rng.below(count);
+
+    return (0..count).map(|monkey| {
+        let items = (0..rng.between(1..=8)).map(|_| rng.between(50..=99)).collect();
+        let operation = match monkey {
+            _ if multiply && monkey == squaring => ('*', None),
+            _ if !multiply || rng.chance(0.6) => ('+', Some(rng.between(1..=8))),
+            _ => ('*', Some(rng.between(2..=19))),
+        };
+        let mut target = || (monkey + 1 + rng.below(count - 1)) % count;
+
+        Plan { items, operation, divisor: divisors[monkey], targets: [target(), target()] }
+    }).collect();
+}
+
+/// Plays the part 1 rounds, telling whether a worry level ever leaves `i64`.
+fn overflows(monkeys: &[Plan]) -> bool {
+    let mut items: Vec<Vec<i64>> = monkeys.iter().map(|monkey| monkey.items.clone()).collect();
+
+    for _ in 0..PART1_ROUNDS {
+        for (monkey, plan) in monkeys.iter().enumerate() {
+            for item in std::mem::take(&mut items[monkey]) {
+                let worry_level = match plan.operation {
+                    ('*', None) => item.checked_mul(item),
+                    ('*', Some(value)) => item.checked_mul(value),
+                    (_, Some(value)) => item.checked_add(value),
+                    _ => item.checked_add(item),
+                };
+                let Some(worry_level) = worry_level.map(|worry_level| worry_level / 3) else {
+                    return true;
+                };
+
+                let target = plan.targets[(worry_level % plan.divisor != 0) as usize];
+                items[target].push(worry_level);
+            }
+        }
+    }
+
+    return false;
+}
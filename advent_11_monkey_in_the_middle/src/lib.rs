@@ -0,0 +1,231 @@
+mod generate;
+
+use aoc_core::parse::{self, numbered_lines};
+use aoc_core::{verbose, ParseError, Puzzle, Result};
+use regex::Regex;
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<i64>,
+    operation: Operation,
+    decision_test: Option<DecisionTest>,
+    inspection_count: usize
+}
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Multiply(i64),
+    Add(i64),
+    MultiplyItself,
+    AddItself,
+    None
+}
+
+#[derive(Debug, Clone)]
+struct DecisionTest {
+    divisible_by: Option<i64>,
+    throw_to_if_true: Option<usize>,
+    throw_to_if_false: Option<usize>
+}
+
+struct PendingItem {
+    to: usize,
+    item_worry_level: i64,
+}
+
+pub struct MonkeyInTheMiddle;
+
+impl Puzzle for MonkeyInTheMiddle {
+    const DAY: u8 = 11;
+
+    type Parsed = Vec<Monkey>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(monkeys: &Self::Parsed) -> Result<Self::Answer1> {
+        part1(monkeys)
+    }
+
+    fn part2(monkeys: &Self::Parsed) -> Result<Self::Answer2> {
+        part2(monkeys)
+    }
+}
+
+fn part1(monkeys: &[Monkey]) -> Result<usize> {
+    return simluate_rounds(monkeys.to_vec(), true, 20);
+}
+
+fn part2(monkeys: &[Monkey]) -> Result<usize> {
+    return simluate_rounds(monkeys.to_vec(), false, 10000);
+}
+
+fn simluate_rounds(mut monkeys: Vec<Monkey>, is_part1: bool, round_count: usize) -> Result<usize> {
+    let mut pending_items: Vec<PendingItem> = Vec::new();
+
+    let mod_values: i64 = monkeys.iter().map(|m| m.decision_test.as_ref().unwrap().divisible_by.unwrap()).product();
+    for _ in 0..round_count {
+        for (monkey_index, monkey) in monkeys.iter_mut().enumerate() {
+            let items_to_add = pending_items.iter().filter(|i| i.to == monkey_index);
+            for item in items_to_add {
+                monkey.items.push(item.item_worry_level);
+            }
+            for i in (0..pending_items.len()).rev() {
+                if pending_items[i].to == monkey_index {
+                    pending_items.remove(i);
+                }
+            }
+
+            if monkey.items.is_empty() {
+                continue;
+            }
+
+            for item_worry_level in monkey.items.iter() {
+                let mut worry_level = *item_worry_level; 
+
+                worry_level = match monkey.operation {
+                    Operation::MultiplyItself => worry_level * worry_level,
+                    Operation::AddItself => worry_level + worry_level,
+                    Operation::Multiply(v) => v * worry_level,
+                    Operation::Add(v) => v + worry_level,
+                    _ => worry_level
+                };
+
+                if is_part1 {
+                    worry_level /= 3;
+                } else {
+                    worry_level %= mod_values;
+                }
+
+                let decision_test = monkey.decision_test.as_ref().expect("Monkey should have decision test");
+                let throw_to = match decision_test {
+                    DecisionTest {divisible_by: Some(d), 
+                                  throw_to_if_true: Some(t), 
+                                  throw_to_if_false: Some(f)} => {
+                                    if worry_level % *d == 0 {
+                                        *t
+                                    }
+                                    else {
+                                        *f
+                                    }
+                                  }
+                    x => panic!("Decision should have all values: divisible by: {:?}, true case: {:?}, false case: {:?}", x.divisible_by, x.throw_to_if_true, x.throw_to_if_false)
+                };
+
+                pending_items.push(PendingItem {to: throw_to, item_worry_level: worry_level});
+            }
+
+            monkey.inspection_count += monkey.items.len();
+            for i in (0..monkey.items.len()).rev() {
+                monkey.items.remove(i);
+            }
+        }
+    }
+
+    verbose!("After {} rounds:", round_count);
+    verbose!("Inspection counts: ");
+    for (i, monkey) in monkeys.iter().enumerate() {
+        verbose!("Monkey {} inspection counts: {}", i, monkey.inspection_count);
+    }
+
+    monkeys.sort_by_key(|m| std::cmp::Reverse(m.inspection_count));
+    verbose!("Most active monkeys scores: {} and {}", monkeys[0].inspection_count, monkeys[1].inspection_count);
+    return Ok(monkeys[0].inspection_count * monkeys[1].inspection_count);
+}
+
+fn parse(input: &str) -> Result<Vec<Monkey>> {
+    let mut monkeys: Vec<Monkey> = Vec::new();
+    let mut headers: Vec<(usize, &str)> = Vec::new();
+    let number_re = Regex::new(r"(\d+)")?;
+    let operation_re = Regex::new(r"^\s*Operation: new = old (\*|\+) (\d+|old)$")?;
+    let test_re = Regex::new(r"^\s*Test: divisible by (\d+)$")?;
+    let true_re = Regex::new(r"^\s*If true: throw to monkey (\d+)$")?;
+    let false_re = Regex::new(r"^\s*If false: throw to monkey (\d+)$")?;
+
+    for (line_number, line) in numbered_lines(input) {
+        if line.starts_with("Monkey") {
+            monkeys.push(Monkey {
+                items: Vec::new(), 
+                operation: Operation::None, 
+                decision_test: None,
+                inspection_count: 0
+            });
+            headers.push((line_number, line));
+            continue;
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let Some(monkey) = monkeys.last_mut() else {
+            return Err(Box::new(ParseError::new(line_number, line, line, "a 'Monkey 0:' header")));
+        };
+        let mut decision_test = monkey.decision_test.clone().unwrap_or(DecisionTest {
+            divisible_by: None,
+            throw_to_if_true: None,
+            throw_to_if_false: None
+        });
+        let capture = |re: &Regex, expected: &str| {
+            re.captures(line)
+                .map(|captures| captures.get(1).unwrap().as_str())
+                .ok_or_else(|| ParseError::new(line_number, line, line.trim(), expected))
+        };
+
+        match line {
+            _ if line.trim().starts_with("Starting items:") => {
+                for item in number_re.captures_iter(line) {
+                    let item_id = parse::number::<i64>(line_number, line, item.get(1).unwrap().as_str())?;
+                    monkey.items.push(item_id);
+                }
+            }
+            _ if line.trim().starts_with("Operation:") => {
+                let captures = operation_re.captures(line)
+                    .ok_or_else(|| ParseError::new(line_number, line, line.trim(), "an operation like 'Operation: new = old * 19'"))?;
+
+                let number_value = captures.get(2).unwrap().as_str();
+                monkey.operation = match &captures[1] {
+                    "*" if number_value == "old" => Operation::MultiplyItself,
+                    "+" if number_value == "old" => Operation::AddItself,
+                    "*" => Operation::Multiply(parse::number(line_number, line, number_value)?),
+                    _ => Operation::Add(parse::number(line_number, line, number_value)?),
+                };
+            }
+            _ if line.trim().starts_with("Test:") => {
+                let number = capture(&test_re, "a test like 'Test: divisible by 23'")?;
+                decision_test.divisible_by = Some(parse::number(line_number, line, number)?);
+            }
+            _ if line.trim().starts_with("If true:") => {
+                let throw_to = capture(&true_re, "a target like 'If true: throw to monkey 2'")?;
+                decision_test.throw_to_if_true = Some(parse::number(line_number, line, throw_to)?);
+            }
+            _ if line.trim().starts_with("If false:") => {
+                let throw_to = capture(&false_re, "a target like 'If false: throw to monkey 3'")?;
+                decision_test.throw_to_if_false = Some(parse::number(line_number, line, throw_to)?);
+            }
+            _ => return Err(Box::new(ParseError::new(line_number, line, line.trim(), "starting items, an operation, a test or a throw target"))),
+        }
+
+        monkey.decision_test = Some(decision_test);
+    }
+
+    if monkeys.len() < 2 {
+        return Err(Box::new(ParseError::end_of_input(input, "at least two monkeys")));
+    }
+
+    for (monkey, (line_number, line)) in monkeys.iter().zip(headers) {
+        let complete = matches!(monkey.decision_test, Some(DecisionTest {
+            divisible_by: Some(_),
+            throw_to_if_true: Some(_),
+            throw_to_if_false: Some(_)
+        }));
+
+        if matches!(monkey.operation, Operation::None) || !complete {
+            return Err(Box::new(ParseError::new(line_number, line, line, "a monkey with an operation, a test and both throw targets")));
+        }
+    }
+
+    return Ok(monkeys);
+}
\ No newline at end of file
@@ -0,0 +1,40 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::HillClimbingAlgorithm;
+
+/// Heightmap of `size` rows, four times as wide, rising from 'a' on the left
+/// to 'z' on the right. Bumps and dips make detours, but one row is left
+/// untouched so the best signal can always be reached.
+impl Generate for HillClimbingAlgorithm {
+    const DEFAULT_SIZE: usize = 41;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let rows = size.max(7);
+        let columns = rows * 4;
+        let path_row = rng.below(rows);
+        let end_column = columns - 1;
+
+        let mut input = String::new();
+        for row in 0..rows {
+            for column in 0..columns {
+                let height = (column * 25 / (columns - 1)) as i64;
+                let height = match (row, column) {
+                    (_, 0) => 0,
+                    _ if row == path_row && column == end_column => 25,
+                    _ if row == path_row || column == end_column => height,
+                    _ if rng.chance(0.2) => (height + rng.between(-3..=3)).clamp(0, 25),
+                    _ => height,
+                };
+
+                input.push(match (row, column) {
+                    _ if row == path_row && column == 0 => 'S',
+                    _ if row == path_row && column == end_column => 'E',
+                    _ => (b'a' + height as u8) as char,
+                });
+            }
+            input.push('\n');
+        }
+
+        return input;
+    }
+}
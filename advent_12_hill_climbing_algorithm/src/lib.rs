@@ -0,0 +1,114 @@
+mod generate;
+
+use std::collections::HashMap;
+
+use aoc_core::geometry::Direction;
+use aoc_core::grid::{Grid, Position};
+use aoc_core::search::{self, Paths};
+use aoc_core::{verbose, ParseError, Puzzle, Result};
+
+const START_SYMBOL: char = 'S';
+const END_SYMBOL: char = 'E';
+
+pub struct Map {
+    heights: Grid<u8>,
+    start: Position,
+    end: Position,
+}
+
+pub struct HillClimbingAlgorithm;
+
+impl Puzzle for HillClimbingAlgorithm {
+    const DAY: u8 = 12;
+
+    type Parsed = Map;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(map: &Self::Parsed) -> Result<Self::Answer1> {
+        part1(map)
+    }
+
+    fn part2(map: &Self::Parsed) -> Result<Self::Answer2> {
+        part2(map)
+    }
+}
+
+fn part1(map: &Map) -> Result<u32> {
+    let paths = search::bfs_until([map.start], |&square| climbable(map, square), |&square| square == map.end);
+
+    return shortest_path(map, &paths);
+}
+
+fn part2(map: &Map) -> Result<u32> {
+    // Searching from every lowest square at once finds the one nearest the signal
+    let lowest_squares = map.heights.iter().filter(|&(_, &height)| height == b'a').map(|(square, _)| square);
+    let paths = search::bfs_until(lowest_squares, |&square| climbable(map, square), |&square| square == map.end);
+
+    return shortest_path(map, &paths);
+}
+
+fn shortest_path(map: &Map, paths: &Paths<Position>) -> Result<u32> {
+    let steps = paths.goal_cost().ok_or(SignalNotReachable)?;
+
+    verbose!("{}", render_path(map, &paths.path(&map.end).unwrap()));
+    return Ok(steps as u32);
+}
+
+/// Squares one step away from `square` that are at most one higher.
+fn climbable(map: &Map, square: Position) -> Vec<Position> {
+    let height = map.heights[square];
+
+    return map.heights.neighbours4(square).filter(|&next| map.heights[next] <= height + 1).collect();
+}
+
+/// The heightmap with the path drawn like in the exercise, an arrow on each
+/// square towards the next one and 'E' at the best signal.
+fn render_path(map: &Map, path: &[Position]) -> String {
+    let mut steps: HashMap<Position, char> = path.windows(2)
+        .filter_map(|step| {
+            let direction = Direction::ALL.into_iter().find(|direction| step[0] + direction.offset() == step[1])?;
+            Some((step[0], direction.arrow()))
+        })
+        .collect();
+    steps.insert(map.end, END_SYMBOL);
+
+    return map.heights.render(|square, _| *steps.get(&square).unwrap_or(&'.'));
+}
+
+#[derive(Debug)]
+pub struct SignalNotReachable;
+
+impl std::error::Error for SignalNotReachable {}
+
+impl std::fmt::Display for SignalNotReachable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "No path climbs up to the best signal square")
+    }
+}
+
+fn parse(input: &str) -> Result<Map> {
+    let mut heights = Grid::parse_rectangle(input, "squares", "a height 'a'-'z', 'S' or 'E'", |c| {
+        match c {
+            'a'..='z' | START_SYMBOL | END_SYMBOL => Some(c as u8),
+            _ => None,
+        }
+    })?;
+
+    let start = heights.find(|&height| height == START_SYMBOL as u8)
+        .ok_or_else(|| ParseError::end_of_input(input, format!("a start square '{}'", START_SYMBOL)))?;
+    let end = heights.find(|&height| height == END_SYMBOL as u8)
+        .ok_or_else(|| ParseError::end_of_input(input, format!("a best signal square '{}'", END_SYMBOL)))?;
+    heights.insert(start, b'a');
+    heights.insert(end, b'z');
+
+    Ok(Map {
+        heights,
+        start,
+        end,
+    })
+}
\ No newline at end of file
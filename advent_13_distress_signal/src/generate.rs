@@ -0,0 +1,25 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::DistressSignal;
+
+/// `size` pairs of packets nested at most four lists deep.
+impl Generate for DistressSignal {
+    const DEFAULT_SIZE: usize = 150;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let pairs: Vec<String> = (0..size.max(1)).map(|_| format!("{}\n{}\n", packet(rng, 0), packet(rng, 0))).collect();
+        return pairs.join("\n");
+    }
+}
+
+fn packet(rng: &mut Rng, depth: usize) -> String {
+    let values: Vec<String> = (0..rng.between(0..=5)).map(|_| {
+        if depth < 4 && rng.chance(0.3) {
+            packet(rng, depth + 1)
+        } else {
+            rng.between(0..=10).to_string()
+        }
+    }).collect();
+
+    return format!("[{}]", values.join(","));
+}
@@ -0,0 +1,222 @@
+mod generate;
+
+use std::cmp::Ordering;
+
+use aoc_core::parse::{self, numbered_lines};
+use aoc_core::{trace, ParseError, Puzzle, Result};
+
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct PacketPair {
+    left: Data,
+    right: Data,
+}
+
+impl PacketPair {
+    fn new(left: Data, right: Data) -> PacketPair {
+        PacketPair {
+            left,
+            right
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub enum Data {
+    Number(u32),
+    List(Vec<Data>)
+}
+
+impl Ord for Data {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return compare((self, other));
+    }
+}
+
+impl PartialOrd for Data {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+pub struct DistressSignal;
+
+impl Puzzle for DistressSignal {
+    const DAY: u8 = 13;
+
+    type Parsed = Vec<PacketPair>;
+    type Answer1 = u32;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(packet_pairs: &Self::Parsed) -> Result<Self::Answer1> {
+        part1(packet_pairs)
+    }
+
+    fn part2(packet_pairs: &Self::Parsed) -> Result<Self::Answer2> {
+        part2(packet_pairs)
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<PacketPair>> {
+    let input = numbered_lines(input).collect::<Vec<(usize, &str)>>();
+
+    let mut packet_pairs = Vec::new();
+    for c in input.chunks(3) {
+        let &[left, right, ref rest @ ..] = c else {
+            let (line_number, line) = c[0];
+            return Err(Box::new(ParseError::end_of_line(line_number, line, "a second packet on the next line")));
+        };
+        if let Some(&(line_number, line)) = rest.first().filter(|(_, line)| !line.is_empty()) {
+            return Err(Box::new(ParseError::new(line_number, line, line, "a blank line between packet pairs")));
+        }
+
+        let left_data = parse_packet(left.0, left.1)?;
+        let right_data = parse_packet(right.0, right.1)?;
+
+        packet_pairs.push(PacketPair::new(left_data, right_data));
+    }
+
+    return Ok(packet_pairs);
+}
+
+fn part1(packet_pairs: &[PacketPair]) -> Result<u32> {
+    let mut correct_indices_sum: u32 = 0;
+    for (i, packet_pair) in packet_pairs.iter().enumerate() {
+        let order = compare((&packet_pair.left, &packet_pair.right));
+
+        if order == Ordering::Less {
+            correct_indices_sum += i as u32 + 1;
+        }
+    }
+
+    return Ok(correct_indices_sum);
+}
+
+fn part2(packet_pairs: &[PacketPair]) -> Result<usize> {
+    let mut data: Vec<Data> = Vec::new();
+    for packet_pair in packet_pairs {
+        data.push(packet_pair.left.clone());
+        data.push(packet_pair.right.clone());
+    }
+    let divider_packet_1 = Data::List(vec![Data::List(vec![Data::Number(2)])]);
+    let divider_packet_2 = Data::List(vec![Data::List(vec![Data::Number(6)])]);
+    data.push(divider_packet_1.clone());
+    data.push(divider_packet_2.clone());
+
+    data.sort();
+
+    for d in &data {
+        trace!("{:?}", d);
+    }
+
+    let index_divider_packet_1 = data.iter().position(|d| d == &divider_packet_1).unwrap() + 1;
+    let index_divider_packet_2 = data.iter().position(|d| d == &divider_packet_2).unwrap() + 1;
+
+    return Ok(index_divider_packet_1 * index_divider_packet_2);
+}
+
+fn compare(packet_pair: (&Data, &Data)) -> Ordering {
+    match packet_pair {
+        (Data::List(ll), Data::List(lr)) => {
+            let mut idx = 0;
+            loop {
+                if ll.len() <= idx || lr.len() <= idx {
+                    if ll.len() < lr.len() {
+                        return Ordering::Less;
+                    } else if ll.len() == lr.len() {
+                        return Ordering::Equal;
+                    } else {
+                        return Ordering::Greater;
+                    }
+                }
+
+                match (&ll[idx], &lr[idx]) {
+                    (Data::Number(l), Data::Number(r)) => {
+                        if l < r {
+                            return Ordering::Less
+                        } else if  l > r {
+                            return Ordering::Greater
+                        }
+                    },
+                    (Data::List(_), Data::Number(r)) => {
+                        let ordering = compare((&ll[idx], &Data::List(vec![Data::Number(*r)])));
+                        if ordering != Ordering::Equal {
+                            return ordering;
+                        }
+                    }
+                    (Data::Number(l), Data::List(_)) => {
+                        let ordering = compare((&Data::List(vec![Data::Number(*l)]), &lr[idx]));
+                        if ordering != Ordering::Equal {
+                            return ordering;
+                        }
+                    }
+                    (Data::List(_), Data::List(_)) => {
+                        let ordering = compare((&ll[idx], &lr[idx]));
+                        if ordering != Ordering::Equal {
+                            return ordering;
+                        }
+                    }
+                };
+                idx += 1;
+            }
+        },
+        _ => panic!("Not expected input")
+    };
+}
+
+fn parse_packet(line_number: usize, line: &str) -> std::result::Result<Data, ParseError> {
+    if !line.starts_with('[') {
+        return Err(ParseError::at_char(line_number, line, 0, "a packet starting with '['"));
+    }
+
+    let mut position = 1;
+    let packet = parse_data(line_number, line, &mut position)?;
+    if position < line.len() {
+        return Err(ParseError::new(line_number, line, &line[position..], "end of the packet"));
+    }
+
+    return Ok(packet);
+}
+
+/// Parses the list whose opening bracket is just before `position`, leaving
+/// `position` after its closing bracket.
+fn parse_data(line_number: usize, line: &str, position: &mut usize) -> std::result::Result<Data, ParseError> {
+    let bytes = line.as_bytes();
+    let mut data_packet: Vec<Data> = Vec::new();
+
+    if bytes.get(*position) == Some(&b']') {
+        *position += 1;
+        return Ok(Data::List(data_packet));
+    }
+
+    loop {
+        match bytes.get(*position) {
+            Some(b'0'..=b'9') => {
+                let start = *position;
+                while bytes.get(*position).is_some_and(u8::is_ascii_digit) {
+                    *position += 1;
+                }
+                data_packet.push(Data::Number(parse::number(line_number, line, &line[start..*position])?));
+            },
+            Some(b'[') => {
+                *position += 1;
+                data_packet.push(parse_data(line_number, line, position)?);
+            },
+            Some(_) => return Err(ParseError::at_char(line_number, line, *position, "a number or '['")),
+            None => return Err(ParseError::end_of_line(line_number, line, "a number or '['")),
+        }
+
+        match bytes.get(*position) {
+            Some(b',') => *position += 1,
+            Some(b']') => {
+                *position += 1;
+                return Ok(Data::List(data_packet));
+            },
+            Some(_) => return Err(ParseError::at_char(line_number, line, *position, "',' or ']'")),
+            None => return Err(ParseError::end_of_line(line_number, line, "',' or ']'")),
+        }
+    }
+}
\ No newline at end of file
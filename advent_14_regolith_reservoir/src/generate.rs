@@ -0,0 +1,31 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::RegolithReservoir;
+
+/// `size` rock paths of two to seven straight segments, below the sand
+/// source and around its column.
+impl Generate for RegolithReservoir {
+    const DEFAULT_SIZE: usize = 150;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size.max(1) {
+            let (mut x, mut y) = (rng.between(450..=550), rng.between(10..=170));
+            let mut points = vec![format!("{},{}", x, y)];
+
+            for segment in 0..rng.between(1..=6) {
+                if segment % 2 == 0 {
+                    x = (x + rng.between(-8..=8)).clamp(450, 550);
+                } else {
+                    y = (y + rng.between(-5..=5)).clamp(10, 170);
+                }
+                points.push(format!("{},{}", x, y));
+            }
+
+            input.push_str(&points.join(" -> "));
+            input.push('\n');
+        }
+
+        return input;
+    }
+}
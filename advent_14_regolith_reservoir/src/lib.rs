@@ -0,0 +1,128 @@
+mod generate;
+mod visualize;
+
+use aoc_core::geometry::Point2;
+use aoc_core::grid::{Grid, Position};
+use aoc_core::parse::{self, numbered_lines};
+use aoc_core::{ParseError, Puzzle, Result};
+
+/// Cave elements keyed by (x, y), y growing downwards.
+pub type Cave = Grid<ElementType>;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ElementType {
+    Rock,
+    Sand
+}
+
+pub struct RegolithReservoir;
+
+impl Puzzle for RegolithReservoir {
+    const DAY: u8 = 14;
+
+    type Parsed = Cave;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(scan: &Self::Parsed) -> Result<Self::Answer1> {
+        part1(scan)
+    }
+
+    fn part2(scan: &Self::Parsed) -> Result<Self::Answer2> {
+        part2(scan)
+    }
+}
+
+const SAND_FALL_COORDINATES: Position = Point2::new(500, 0);
+
+fn part1(scan: &Cave) -> Result<usize> {
+    let grid = simulate_sand_fall(scan.clone(), false, |_| true);
+
+    let sand_elements_count = grid.iter().filter(|&e| e.1 == &ElementType::Sand).count();
+    Ok(sand_elements_count)
+}
+
+fn part2(scan: &Cave) -> Result<usize> {
+    let grid = simulate_sand_fall(scan.clone(), true, |_| true);
+
+    let sand_elements_count = grid.iter().filter(|&e| e.1 == &ElementType::Sand).count();
+    Ok(sand_elements_count)
+}
+
+/// Pours sand until it flows into the abyss, or without one until the
+/// source is blocked. `on_rest` sees the cave after every unit comes to rest
+/// and stops the pouring by returning false.
+fn simulate_sand_fall(mut grid: Cave, has_floor: bool, mut on_rest: impl FnMut(&Cave) -> bool) -> Cave {
+    let lowest_y_point = grid.bounds().map_or(0, |bounds| bounds.max.y);
+    let floor_y_coordinate = lowest_y_point + 2;
+
+    while !grid.contains(SAND_FALL_COORDINATES) {
+        let mut fall_sand_coordinates = SAND_FALL_COORDINATES;
+
+        while let Some(p) = drop(&grid, fall_sand_coordinates) {
+            fall_sand_coordinates = p;
+
+            if !has_floor && p.y > lowest_y_point {
+                return grid;
+            } else if has_floor && p.y + 1 == floor_y_coordinate {
+                break;
+            }
+        }
+
+        grid.insert(fall_sand_coordinates, ElementType::Sand);
+        if !on_rest(&grid) {
+            break;
+        }
+    }
+
+    return grid;
+}
+
+fn drop(grid: &Cave, curr_pos: Position) -> Option<Position> {
+    let possible_paths: [Position; 3] = [Point2::new(0, 1), Point2::new(-1, 1), Point2::new(1, 1)];
+
+    for p in possible_paths {
+        let possible_position = curr_pos + p;
+        if !grid.contains(possible_position) {
+            return Some(possible_position);
+        }
+    }
+
+    return None;
+}
+
+/// Rock positions of the scanned cave.
+fn parse(input: &str) -> Result<Cave> {
+    let mut grid: Cave = Grid::sparse();
+
+    for (line_number, line) in numbered_lines(input) {
+        let mut points = Vec::new();
+        for point in line.split(" -> ") {
+            let (x, y) = point.split_once(',')
+                .ok_or_else(|| ParseError::new(line_number, line, point, "a point like '498,4'"))?;
+            points.push((point, (parse::number::<i32>(line_number, line, x)?, parse::number::<i32>(line_number, line, y)?)));
+        }
+
+        for pair in points.windows(2) {
+            let [(_, coordinates_1), (point, coordinates_2)] = pair else { continue };
+
+            let rock_coordinates = match (*coordinates_1, *coordinates_2) {
+                ((x_1, y_1), (x_2, y_2)) if x_1 == x_2 && y_1 > y_2 => (y_2..=y_1).collect::<Vec<i32>>().iter().map(|y| (x_1, *y)).collect::<Vec<(i32, i32)>>(),
+                ((x_1, y_1), (x_2, y_2)) if x_1 == x_2 && y_1 <= y_2 => (y_1..=y_2).collect::<Vec<i32>>().iter().map(|y| (x_1, *y)).collect::<Vec<(i32, i32)>>(),
+                ((x_1, y_1), (x_2, y_2)) if x_1 > x_2 && y_1 == y_2 => (x_2..=x_1).collect::<Vec<i32>>().iter().map(|x| (*x, y_1)).collect::<Vec<(i32, i32)>>(),
+                ((x_1, y_1), (x_2, y_2)) if x_1 < x_2 && y_1 == y_2 => (x_1..=x_2).collect::<Vec<i32>>().iter().map(|x| (*x, y_1)).collect::<Vec<(i32, i32)>>(),
+                _ => return Err(Box::new(ParseError::new(line_number, line, point, "a point in a straight line from the previous one"))),
+            };
+
+            for coordinates in rock_coordinates {
+                grid.insert(coordinates.into(), ElementType::Rock);
+            }
+        }
+    }
+
+    return Ok(grid);
+}
\ No newline at end of file
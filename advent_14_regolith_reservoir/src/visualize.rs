@@ -0,0 +1,42 @@
+use aoc_core::geometry::Point2;
+use aoc_core::grid::Bounds;
+use aoc_core::visualize::{Frames, Visualize};
+use aoc_core::Result;
+
+use crate::{simulate_sand_fall, Cave, ElementType, RegolithReservoir, SAND_FALL_COORDINATES};
+
+/// Part 1's sand one resting unit per frame, until it flows into the abyss:
+/// rock `#`, sand `o` and the source `+`, framed around the scanned rock.
+impl Visualize for RegolithReservoir {
+    fn visualize(scan: &Cave, frames: &mut Frames) -> Result<()> {
+        let Some(rock) = scan.bounds() else {
+            frames.push("No rock in the cave", String::new());
+            return Ok(());
+        };
+        let view = Bounds {
+            min: Point2::new(rock.min.x.min(SAND_FALL_COORDINATES.x) - 1, SAND_FALL_COORDINATES.y),
+            max: Point2::new(rock.max.x.max(SAND_FALL_COORDINATES.x) + 1, rock.max.y + 1),
+        };
+
+        if !frames.push("Scanned cave", draw(scan, view)) {
+            return Ok(());
+        }
+
+        let mut units = 0;
+        simulate_sand_fall(scan.clone(), false, |cave| {
+            units += 1;
+            frames.push(format!("Units of sand at rest: {}", units), draw(cave, view))
+        });
+
+        return Ok(());
+    }
+}
+
+fn draw(cave: &Cave, view: Bounds) -> String {
+    return view.render(|position| match cave.get(position) {
+        Some(ElementType::Rock) => '#',
+        Some(ElementType::Sand) => 'o',
+        None if position == SAND_FALL_COORDINATES => '+',
+        None => '.',
+    });
+}
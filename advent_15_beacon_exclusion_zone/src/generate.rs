@@ -0,0 +1,32 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::BeaconExclusionZone;
+
+/// Reports of `size` sensors spread over the search area, each with its
+/// closest beacon up to a million positions away.
+impl Generate for BeaconExclusionZone {
+    const DEFAULT_SIZE: usize = 30;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut sensors: Vec<(i64, i64)> = Vec::new();
+        let mut input = String::new();
+
+        while sensors.len() < size.max(1) {
+            let sensor = (rng.between(0..=4_000_000), rng.between(0..=4_000_000));
+            if sensors.contains(&sensor) {
+                continue;
+            }
+            sensors.push(sensor);
+
+            let distance = rng.between(1..=1_000_000);
+            let dx = rng.between(0..=distance);
+            let dy = distance - dx;
+            let beacon_x = sensor.0 + if rng.chance(0.5) { dx } else { -dx };
+            let beacon_y = sensor.1 + if rng.chance(0.5) { dy } else { -dy };
+
+            input.push_str(&format!("Sensor at x={}, y={}: closest beacon is at x={}, y={}\n", sensor.0, sensor.1, beacon_x, beacon_y));
+        }
+
+        return input;
+    }
+}
@@ -0,0 +1,170 @@
+mod generate;
+
+use std::collections::HashSet;
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+use aoc_core::geometry::Point2;
+use aoc_core::parse::{self, numbered_lines};
+use aoc_core::{verbose, DayConfig, ParseError, Puzzle, Result};
+use regex::Regex;
+
+/// Sensor and beacon positions run into the millions, hence `i64`.
+pub type Sensor = Point2<i64>;
+pub type Beacon = Point2<i64>;
+
+/// Row checked for beacon-free positions in part 1.
+const FILL_ROW_Y: i64 = 2000000;
+/// Upper bound of both coordinates of the distress beacon in part 2.
+const MAX_COORDINATE: i64 = 20;
+
+pub struct Readings {
+    beacons_map: HashMap<Sensor, Beacon>,
+    fill_row_y: i64,
+    max_coordinate: i64,
+}
+
+pub struct BeaconExclusionZone;
+
+impl Puzzle for BeaconExclusionZone {
+    const DAY: u8 = 15;
+
+    type Parsed = Readings;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(readings: &Self::Parsed) -> Result<Self::Answer1> {
+        part1(&readings.beacons_map, readings.fill_row_y)
+    }
+
+    fn part2(readings: &Self::Parsed) -> Result<Self::Answer2> {
+        part2(&readings.beacons_map, readings.max_coordinate)
+    }
+
+    fn configure(readings: &mut Self::Parsed, config: &DayConfig) -> Result<()> {
+        readings.fill_row_y = config.get("fill_row_y", FILL_ROW_Y)?;
+        readings.max_coordinate = config.get_at_least("max_coordinate", MAX_COORDINATE, 0)?;
+        Ok(())
+    }
+}
+
+fn part1(beacons_map: &HashMap<Sensor, Beacon>, fill_row_y: i64) -> Result<usize> {
+    let row_set = get_row_spots(beacons_map, fill_row_y);
+
+    let same_row_sensors: HashSet<i64> = HashSet::from_iter(beacons_map.iter().filter(|&m| m.1.y == fill_row_y).map(|i| i.1.x));
+    let row_sensors_len = same_row_sensors.len();
+    Ok(row_set.len() - row_sensors_len)
+}
+
+fn part2(beacons_map: &HashMap<Sensor, Beacon>, max_coordinate: i64) -> Result<i64> {
+
+    for row in 0..=max_coordinate {
+        let mut row_data = vec![0..=max_coordinate];
+
+        for (sensor, beacon) in beacons_map.iter() {
+            let radius = sensor.manhattan(*beacon);
+            let top: i64 = 0.max(sensor.y - radius);
+            let bottom: i64 = max_coordinate.min(sensor.y + radius);
+
+            if top > row || bottom < row {
+                continue;
+            }
+
+            let dist = (sensor.y - row).abs();
+            let min_x = 0.max(sensor.x - (radius - dist));
+            let max_x = max_coordinate.min(sensor.x + (radius - dist));
+
+            let mut new_range: Vec<RangeInclusive<i64>> = Vec::new();
+            for r in &row_data {
+                let start = *r.start();
+                if start > max_x {
+                    new_range.push(r.clone());
+                    continue;
+                }
+
+                let end = *r.end();
+                if end < min_x {
+                    new_range.push(r.clone());
+                    continue;
+                }
+ 
+                if start < min_x {
+                    new_range.push(start..=min_x - 1);
+                }
+
+                if end > max_x {
+                    new_range.push(max_x + 1..=end);
+                }
+
+            }
+            row_data = new_range; 
+        }
+
+        if !row_data.is_empty() {
+            let x = *row_data[0].start();
+            verbose!("Distress beacon row {}: {:?}", row, row_data);
+            return Ok(x * 4000000 + row);
+        }
+    }
+
+    Err(Box::new(DistressBeaconNotFound))
+}
+
+#[derive(Debug)]
+pub struct DistressBeaconNotFound;
+
+impl std::error::Error for DistressBeaconNotFound {}
+
+impl std::fmt::Display for DistressBeaconNotFound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Every position is covered by a sensor, distress beacon was not found")
+    }
+}
+
+fn get_row_spots(beacons_map: &HashMap<Sensor, Beacon>, y: i64) -> HashSet<i64> {
+    let mut row_set: HashSet<i64> = HashSet::new();
+    for sb in beacons_map {
+        let (sensor, beacon) = sb;
+        let radius = sensor.manhattan(*beacon);
+        let distance_to_row_y = (sensor.y - y).abs();
+
+        if distance_to_row_y > radius {
+            continue;
+        }
+
+        let remainder = radius - distance_to_row_y;
+
+        let left_x = sensor.x - remainder;
+        let right_x = sensor.x + remainder;
+
+        for x in left_x..=right_x {
+            row_set.insert(x);
+        }
+    }
+
+    return row_set;
+}
+
+/// Every sensor with the closest beacon it detects.
+fn parse(input: &str) -> Result<Readings> {
+    let regex = Regex::new(r"^Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)$")?;
+
+    let mut beacons_map: HashMap<Sensor, Beacon> = HashMap::new();
+    for (line_number, line) in numbered_lines(input) {
+        let captures = regex.captures(line).ok_or_else(|| {
+            ParseError::new(line_number, line, line, "a report like 'Sensor at x=2, y=18: closest beacon is at x=-2, y=15'")
+        })?;
+        let coordinate = |i: usize| parse::number::<i64>(line_number, line, captures.get(i).unwrap().as_str());
+
+        let sensor = Sensor::new(coordinate(1)?, coordinate(2)?);
+        let beacon = Beacon::new(coordinate(3)?, coordinate(4)?);
+
+        beacons_map.insert(sensor, beacon);
+    }
+
+    return Ok(Readings { beacons_map, fill_row_y: FILL_ROW_Y, max_coordinate: MAX_COORDINATE });
+}
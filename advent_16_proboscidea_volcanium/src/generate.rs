@@ -0,0 +1,55 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::ProboscideaVolcanium;
+
+const START: &str = "AA";
+
+/// Scan of `size` valves joined by tunnels into one cave system, about a
+/// quarter of them with a working flow rate. Valve AA is stuck at zero,
+/// like in the real input.
+impl Generate for ProboscideaVolcanium {
+    const DEFAULT_SIZE: usize = 57;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let count = size.clamp(2, 26 * 26);
+        let mut names = vec![String::from(START)];
+        while names.len() < count {
+            let name: String = (0..2).map(|_| rng.char_of("ABCDEFGHIJKLMNOPQRSTUVWXYZ")).collect();
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+
+        // A spanning tree keeps every valve reachable, extra tunnels add loops
+        let mut tunnels: Vec<Vec<usize>> = vec![Vec::new(); count];
+        let mut connect = |a: usize, b: usize| {
+            if a != b && !tunnels[a].contains(&b) {
+                tunnels[a].push(b);
+                tunnels[b].push(a);
+            }
+        };
+        for valve in 1..count {
+            connect(valve, rng.below(valve));
+        }
+        for _ in 0..count / 3 {
+            connect(rng.below(count), rng.below(count));
+        }
+
+        let mut input = String::new();
+        for (valve, name) in names.iter().enumerate() {
+            let flow_rate = match valve {
+                0 => 0,
+                _ if rng.chance(0.25) => rng.between(1..=25),
+                _ => 0,
+            };
+            let neighbours: Vec<&str> = tunnels[valve].iter().map(|&neighbour| names[neighbour].as_str()).collect();
+
+            input.push_str(&match neighbours.as_slice() {
+                [neighbour] => format!("Valve {} has flow rate={}; tunnel leads to valve {}\n", name, flow_rate, neighbour),
+                _ => format!("Valve {} has flow rate={}; tunnels lead to valves {}\n", name, flow_rate, neighbours.join(", ")),
+            });
+        }
+
+        return input;
+    }
+}
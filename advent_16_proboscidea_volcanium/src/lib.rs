@@ -0,0 +1,246 @@
+mod generate;
+
+use std::collections::HashSet;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+use aoc_core::parse::{self, numbered_lines};
+use aoc_core::search;
+use aoc_core::{ParseError, Puzzle, Result};
+use regex::Regex;
+
+#[derive(Debug, Clone)]
+struct State {
+    curr_loc: Name,
+    minutes_left: u32,
+    opened_valves: HashSet<Name>,
+    helper: bool
+}
+
+impl PartialEq for State {
+    fn eq(&self, other: &Self) -> bool {
+        self.curr_loc == other.curr_loc &&
+        self.minutes_left == other.minutes_left &&
+        self.opened_valves == other.opened_valves &&
+        self.helper == other.helper
+    }
+}
+
+impl Eq for State {}
+
+impl Hash for State {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.curr_loc.hash(state);
+        self.minutes_left.hash(state);
+        self.helper.hash(state);
+        let vec = self.opened_valves.iter().collect::<Vec<&Name>>();
+        for v in vec {
+            v.hash(state);
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Valve {
+    name: Name,
+    flow_rate: u32,
+    neighbors: Vec<String>
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Name([char; 2]);
+
+impl fmt::Debug for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b] = self.0;
+        write!(f, "{}{}", a, b)
+    }
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl Name {
+    fn parse(i: &str) -> Option<Name> {
+        if i.len() == 2 {
+            let mut chars = i.chars();
+            Some(Name([chars.next().unwrap(), chars.next().unwrap()]))
+        } else {
+            None
+        }
+    }
+}
+
+pub struct ProboscideaVolcanium;
+
+impl Puzzle for ProboscideaVolcanium {
+    const DAY: u8 = 16;
+
+    type Parsed = HashMap<Name, Valve>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(valves: &Self::Parsed) -> Result<Self::Answer1> {
+        part1(valves)
+    }
+
+    fn part2(valves: &Self::Parsed) -> Result<Self::Answer2> {
+        part2(valves)
+    }
+}
+
+fn part1(valves: &HashMap<Name, Valve>) -> Result<u32> {
+    let mut valve_shortcuts: HashMap<Name, HashMap<Name, u32>> = HashMap::new();
+    for v in valves.keys() {
+        valve_shortcuts.insert(*v, shortcuts(v, valves));
+    }
+
+    let state = State {
+        curr_loc: Name::parse("AA").unwrap(),
+        minutes_left: 30,
+        opened_valves: HashSet::new(),
+        helper: false
+    };
+
+    let max_flow = Search { seen: HashMap::new() }.dfs(&state, valves, &valve_shortcuts);
+    Ok(max_flow)
+}
+
+fn part2(valves: &HashMap<Name, Valve>) -> Result<u32> {
+    let mut valve_shortcuts: HashMap<Name, HashMap<Name, u32>> = HashMap::new();
+    for v in valves.keys() {
+        valve_shortcuts.insert(*v, shortcuts(v, valves));
+    }
+
+    let state = State {
+        curr_loc: Name::parse("AA").unwrap(),
+        minutes_left: 26,
+        opened_valves: HashSet::new(),
+        helper: true
+    };
+
+    let max_flow = Search { seen: HashMap::new() }.dfs(&state, valves, &valve_shortcuts);
+    Ok(max_flow)
+}
+
+#[derive(Debug)]
+struct Search {
+    seen: HashMap<State, u32>
+}
+
+impl Search {
+    fn dfs(
+        &mut self,
+        state: &State,
+        valves: &HashMap<Name, Valve>,
+        shortcuts: &HashMap<Name, HashMap<Name, u32>>
+    ) -> u32 {
+        if let Some(answer) = self.seen.get(state) {
+            return *answer;
+        }
+
+        let mut max_flow = if state.helper {
+            self.dfs(&State {
+                curr_loc: Name::parse("AA").unwrap(),
+                minutes_left: 26,
+                opened_valves: state.opened_valves.clone(),
+                helper: false
+            },
+            valves, 
+            shortcuts
+        )
+        } else {
+            0
+        };
+
+        if !state.opened_valves.contains(&state.curr_loc) && state.minutes_left > 0 {
+            let mut opened_valves = state.opened_valves.clone();
+            opened_valves.insert(state.curr_loc);
+            let flow = valves.get(&state.curr_loc).unwrap().flow_rate * (state.minutes_left - 1);
+
+            max_flow = max_flow.max(
+                self.dfs(&State {
+                    curr_loc: state.curr_loc,
+                    minutes_left: state.minutes_left - 1,
+                    opened_valves,
+                    helper: state.helper
+                },
+                valves, 
+                shortcuts
+            ) + flow)
+        }
+
+        let map = shortcuts.get(&state.curr_loc).unwrap();
+        for (dest, cost) in map  {
+            if *cost < state.minutes_left {
+                max_flow = max_flow.max(
+                    self.dfs(&State {
+                        curr_loc: *dest,
+                        minutes_left: state.minutes_left - *cost,
+                        opened_valves: state.opened_valves.clone(),
+                        helper: state.helper
+                    },
+                    valves,
+                    shortcuts
+                ));
+            }
+        }
+
+        self.seen.insert(state.clone(), max_flow);
+        max_flow
+    }
+}
+
+/// Minutes from `start` to every other valve worth opening.
+fn shortcuts(start: &Name, valves: &HashMap<Name, Valve>) -> HashMap<Name, u32> {
+    let paths = search::bfs([*start], |name| {
+        valves[name].neighbors.iter().map(|path| Name::parse(path).unwrap()).collect::<Vec<Name>>()
+    });
+
+    paths.iter()
+        .filter(|&(name, _)| valves[name].flow_rate > 0 && name != start)
+        .map(|(name, &dist)| (*name, dist as u32))
+        .collect()
+}
+
+fn parse(input: &str) -> Result<HashMap<Name, Valve>> {
+    let regex = Regex::new(r"^Valve ([A-Z]{2}) has flow rate=(\d+); tunnels? leads? to valves? ([A-Z]{2}(?:, [A-Z]{2})*)$")?;
+
+    let mut valves: HashMap<Name, Valve> = HashMap::new();
+    let mut tunnels = Vec::new();
+    for (line_number, line) in numbered_lines(input) {
+        let captures = regex.captures(line).ok_or_else(|| {
+            ParseError::new(line_number, line, line, "a valve like 'Valve AA has flow rate=0; tunnels lead to valves DD, II'")
+        })?;
+        let valve_name = captures.get(1).unwrap().as_str();
+        let flow_rate = parse::number::<u32>(line_number, line, captures.get(2).unwrap().as_str())?;
+
+        let mut neighbors: Vec<String> = Vec::new();
+        for neighbor in captures.get(3).unwrap().as_str().split(", ") {
+            neighbors.push(String::from(neighbor));
+            tunnels.push((line_number, line, neighbor));
+        }
+
+        let name = Name::parse(valve_name).unwrap();
+        _ = valves.insert(name, Valve {name, flow_rate, neighbors})
+    }
+
+    for (line_number, line, neighbor) in tunnels {
+        if !valves.contains_key(&Name::parse(neighbor).unwrap()) {
+            return Err(Box::new(ParseError::new(line_number, line, neighbor, "a valve listed in the scan")));
+        }
+    }
+    if !valves.contains_key(&Name::parse("AA").unwrap()) {
+        return Err(Box::new(ParseError::end_of_input(input, "a starting valve 'AA'")));
+    }
+
+    Ok(valves)
+}
\ No newline at end of file
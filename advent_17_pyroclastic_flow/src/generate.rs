@@ -0,0 +1,13 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::PyroclasticFlow;
+
+/// Jet pattern of `size` pushes.
+impl Generate for PyroclasticFlow {
+    const DEFAULT_SIZE: usize = 10091;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let jets: String = (0..size.max(1)).map(|_| rng.char_of("<>")).collect();
+        return jets + "\n";
+    }
+}
@@ -0,0 +1,222 @@
+mod generate;
+mod visualize;
+
+use aoc_core::geometry::Point2;
+use aoc_core::grid::{Grid, Position};
+use aoc_core::parse::numbered_lines;
+use aoc_core::{verbose, DayConfig, ParseError, Part, Puzzle, Result, Unsolved};
+
+struct Chamber {
+    fallen_rocks: u32,
+    /// Cells of settled rocks, y growing upwards from the floor at 0.
+    rocks: Grid<()>,
+    jets: Vec<char>,
+    jet_index: usize
+}
+
+impl Chamber {
+    const WIDTH: i32 = 7;
+    /// Rocks dropped in part 1.
+    const MAX_ROCK_COUNT: u32 = 2022;
+
+    fn new(jets: Vec<char>) -> Chamber {
+        Chamber {
+            fallen_rocks: 0,
+            rocks: Grid::sparse(),
+            jets,
+            jet_index: 0
+        }
+    }
+
+    fn get_jet(&mut self) -> char {
+        if self.jet_index >= self.jets.len() {
+            self.jet_index = 0;
+        }
+
+        let index = self.jet_index;
+        self.jet_index += 1;
+        self.jets[index]
+    }
+
+    fn highest_point(&self) -> i32 {
+        self.rocks.bounds().map_or(0, |bounds| bounds.max.y)
+    }
+
+    /// Whether the rock is between the walls, above the floor and clear of
+    /// settled rocks.
+    fn fits(&self, rock: &Rock) -> bool {
+        rock.get_coordinates().iter()
+            .all(|&cell| (0..Chamber::WIDTH).contains(&cell.x) && cell.y > 0 && !self.rocks.contains(cell))
+    }
+
+    fn settle(&mut self, rock: &Rock) {
+        for cord in rock.get_coordinates() {
+            self.rocks.insert(cord, ());
+        }
+        self.fallen_rocks += 1;
+    }
+}
+
+pub struct Jets {
+    pattern: Vec<char>,
+    max_rock_count: u32,
+}
+
+#[derive(Clone, Copy)]
+struct Rock {
+    rock_type: RockType,
+    coordinates: Position
+}
+
+impl Rock {
+    const ROCK_TYPES_COUNT: u32 = 5;
+
+    fn new(index: u32, y_point: i32) -> Rock {
+        let mut index = index;
+
+        if index >= Rock::ROCK_TYPES_COUNT {
+            index = 0;
+        }
+
+        let rock_type = match index {
+            x if x == RockType::Horizontal as u32 => RockType::Horizontal,
+            x if x == RockType::Cross as u32 => RockType::Cross,
+            x if x == RockType::LShape as u32 => RockType::LShape,
+            x if x == RockType::Vertical as u32 => RockType::Vertical,
+            x if x == RockType::Square as u32 => RockType::Square,
+            _ => panic!("Not covered rock type")
+        };
+
+        Rock {
+            rock_type,
+            coordinates: Point2::new(2, y_point)
+        }
+    }
+
+    /// The same rock shifted by `offset`.
+    fn moved(&self, offset: Position) -> Rock {
+        Rock {
+            rock_type: self.rock_type,
+            coordinates: self.coordinates + offset
+        }
+    }
+
+    fn get_coordinates(&self) -> Vec<Position> {
+        let rock_type_coord = match self.rock_type {
+            RockType::Horizontal => vec![(0, 0), (1, 0), (2, 0), (3, 0)],
+            RockType::Cross => vec![(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)],
+            RockType::LShape => vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+            RockType::Vertical => vec![(0, 0), (0, 1), (0, 2), (0, 3)],
+            RockType::Square => vec![(0, 0), (0, 1), (1, 0), (1, 1)]
+        };
+
+        rock_type_coord.into_iter()
+                       .map(|offset| self.coordinates + Point2::from(offset))
+                       .collect::<Vec<Position>>()
+    }
+}
+
+#[derive(Clone, Copy)]
+enum RockType {
+    Horizontal,
+    Cross,
+    LShape,
+    Vertical,
+    Square
+}
+
+pub struct PyroclasticFlow;
+
+impl Puzzle for PyroclasticFlow {
+    const DAY: u8 = 17;
+
+    type Parsed = Jets;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(jets: &Self::Parsed) -> Result<Self::Answer1> {
+        part1(&jets.pattern, jets.max_rock_count)
+    }
+
+    fn part2(jets: &Self::Parsed) -> Result<Self::Answer2> {
+        part2(&jets.pattern)
+    }
+
+    fn configure(jets: &mut Self::Parsed, config: &DayConfig) -> Result<()> {
+        jets.max_rock_count = config.get_at_least("max_rock_count", Chamber::MAX_ROCK_COUNT, 1)?;
+        Ok(())
+    }
+}
+
+/// Jet pattern, one `<` or `>` per push.
+fn parse(input: &str) -> Result<Jets> {
+    let mut lines = numbered_lines(input.trim_end());
+    let Some((line_number, line)) = lines.next() else {
+        return Err(Box::new(ParseError::end_of_input(input, "a line of jets")));
+    };
+
+    if let Some(i) = line.find(|c: char| c != '<' && c != '>') {
+        return Err(Box::new(ParseError::at_char(line_number, line, i, "a jet '<' or '>'")));
+    }
+    if let Some((line_number, line)) = lines.next() {
+        return Err(Box::new(ParseError::new(line_number, line, line, "a single line of jets")));
+    }
+
+    return Ok(Jets { pattern: line.chars().collect(), max_rock_count: Chamber::MAX_ROCK_COUNT });
+}
+
+fn part1(jets: &[char], max_rock_count: u32) -> Result<u32> {
+    let chamber = simulate(jets, max_rock_count, |_, _| true);
+
+    verbose!("Rocks fallen: {}", chamber.fallen_rocks);
+    Ok(chamber.highest_point() as u32)
+}
+
+/// Drops `max_rock_count` rocks into the chamber. `watch` sees every falling
+/// rock after each push and fall, and stops the simulation by returning false.
+fn simulate(jets: &[char], max_rock_count: u32, mut watch: impl FnMut(&Chamber, &Rock) -> bool) -> Chamber {
+    let mut chamber = Chamber::new(jets.to_vec());
+    let mut rock_index = 0;
+    while chamber.fallen_rocks != max_rock_count {
+        if rock_index >= Rock::ROCK_TYPES_COUNT {
+            rock_index = 0;
+        }
+
+        let mut rock = Rock::new(rock_index, chamber.highest_point() + 4);
+        loop {
+            let pushed = match chamber.get_jet() {
+                '<' => rock.moved(Point2::new(-1, 0)),
+                '>' => rock.moved(Point2::new(1, 0)),
+                other => panic!("Jet movement is invalid {}", other)
+            };
+            if chamber.fits(&pushed) {
+                rock = pushed;
+            }
+
+            let fallen = rock.moved(Point2::new(0, -1));
+            let falls = chamber.fits(&fallen);
+            if falls {
+                rock = fallen;
+            }
+            if !watch(&chamber, &rock) {
+                return chamber;
+            }
+            if !falls {
+                chamber.settle(&rock);
+                break;
+            }
+        }
+
+        rock_index += 1;
+    }
+
+    chamber
+}
+
+fn part2(_jets: &[char]) -> Result<u64> {
+    Err(Box::new(Unsolved(Part::Two)))
+}
@@ -0,0 +1,54 @@
+use aoc_core::geometry::Point2;
+use aoc_core::visualize::{Frames, Visualize};
+use aoc_core::Result;
+
+use crate::{simulate, Chamber, Jets, PyroclasticFlow, Rock};
+
+/// Rows of the chamber shown, from just above the falling rock down.
+const HEIGHT: i32 = 24;
+
+/// Part 1's rocks one push and fall per frame, drawn like the puzzle does:
+/// the falling rock `@`, settled rock `#`, the walls and, while it is in
+/// view, the floor.
+impl Visualize for PyroclasticFlow {
+    fn visualize(jets: &Jets, frames: &mut Frames) -> Result<()> {
+        if !frames.push("Empty chamber", draw(&Chamber::new(jets.pattern.clone()), None)) {
+            return Ok(());
+        }
+
+        simulate(&jets.pattern, jets.max_rock_count, |chamber, rock| {
+            let caption = format!("Rock {}, tower {} units tall", chamber.fallen_rocks + 1, chamber.highest_point());
+            frames.push(caption, draw(chamber, Some(rock)))
+        });
+
+        return Ok(());
+    }
+}
+
+fn draw(chamber: &Chamber, rock: Option<&Rock>) -> String {
+    let falling = rock.map_or_else(Vec::new, |rock| rock.get_coordinates());
+    let top = (chamber.highest_point() + 7).max(HEIGHT);
+    let bottom = top - HEIGHT + 1;
+
+    let mut picture = String::new();
+    for y in (bottom..=top).rev() {
+        picture.push('|');
+        for x in 0..Chamber::WIDTH {
+            let position = Point2::new(x, y);
+            let tile = if falling.contains(&position) {
+                '@'
+            } else if chamber.rocks.contains(position) {
+                '#'
+            } else {
+                '.'
+            };
+            picture.push(tile);
+        }
+        picture.push_str("|\n");
+    }
+    if bottom == 1 {
+        picture.push_str(&format!("+{}+\n", "-".repeat(Chamber::WIDTH as usize)));
+    }
+
+    return picture;
+}
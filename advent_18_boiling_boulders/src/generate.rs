@@ -0,0 +1,41 @@
+use std::collections::HashSet;
+
+use aoc_core::generate::{Generate, Rng};
+
+use crate::BoilingBoulders;
+
+/// Droplet of `size` cubes grown from the middle of a 20 by 20 by 20 space,
+/// so it has pockets of trapped air like the real scan.
+impl Generate for BoilingBoulders {
+    const DEFAULT_SIZE: usize = 2700;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.clamp(1, 21 * 21 * 21);
+        let mut cubes = vec![(10, 10, 10)];
+        let mut seen = HashSet::from([(10, 10, 10)]);
+
+        while cubes.len() < size {
+            let &(x, y, z) = rng.pick(&cubes);
+            let cube = match rng.below(6) {
+                0 => (x + 1, y, z),
+                1 => (x - 1, y, z),
+                2 => (x, y + 1, z),
+                3 => (x, y - 1, z),
+                4 => (x, y, z + 1),
+                _ => (x, y, z - 1),
+            };
+
+            let inside = [cube.0, cube.1, cube.2].iter().all(|c| (0..=20).contains(c));
+            if inside && seen.insert(cube) {
+                cubes.push(cube);
+            }
+        }
+
+        let mut input = String::new();
+        for (x, y, z) in cubes {
+            input.push_str(&format!("{},{},{}\n", x, y, z));
+        }
+
+        return input;
+    }
+}
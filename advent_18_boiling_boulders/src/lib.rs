@@ -0,0 +1,102 @@
+mod generate;
+
+use std::collections::HashSet;
+
+use aoc_core::geometry::Point3;
+use aoc_core::parse::{self, numbered_lines};
+use aoc_core::search;
+use aoc_core::{ParseError, Puzzle, Result};
+
+struct State {
+    surface_area: u32
+}
+
+impl State {
+    fn new() -> State {
+        State {
+            surface_area: 0
+        }
+    }
+
+    fn add(&mut self) {
+        self.surface_area += 1;
+    }
+}
+
+pub type Coordinates = Point3<i32>;
+
+pub struct BoilingBoulders;
+
+impl Puzzle for BoilingBoulders {
+    const DAY: u8 = 18;
+
+    type Parsed = HashSet<Coordinates>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(cubes: &Self::Parsed) -> Result<Self::Answer1> {
+        part1(cubes)
+    }
+
+    fn part2(cubes: &Self::Parsed) -> Result<Self::Answer2> {
+        part2(cubes)
+    }
+}
+
+fn part1(cubes: &HashSet<Coordinates>) -> Result<u32> {
+    let mut state = State::new();
+    for c in cubes {
+        for side in c.neighbours6() {
+            if !cubes.contains(&side) {
+                state.add();
+            }
+        }
+    }
+
+    Ok(state.surface_area)
+}
+
+fn part2(cubes: &HashSet<Coordinates>) -> Result<u32> {
+    // Steam flows around the droplet within a box one cube larger than it
+    let Some((min, max)) = Point3::bounds(cubes.iter().copied()) else {
+        return Ok(0);
+    };
+    let margin = Point3::new(1, 1, 1);
+    let (min, max) = (min - margin, max + margin);
+    let in_box = |c: &Coordinates| {
+        (min.x..=max.x).contains(&c.x) && (min.y..=max.y).contains(&c.y) && (min.z..=max.z).contains(&c.z)
+    };
+
+    let steam = search::flood_fill([min], |pos| {
+        pos.neighbours6().into_iter().filter(|next| in_box(next) && !cubes.contains(next)).collect::<Vec<Coordinates>>()
+    });
+
+    // Every side of a cube touched by steam is on the outer surface
+    let count = steam.iter()
+        .flat_map(|pos| pos.neighbours6())
+        .filter(|side| cubes.contains(side))
+        .count();
+
+    Ok(count as u32)
+}
+
+fn parse(input: &str) -> Result<HashSet<Coordinates>> {
+    let mut cubes: HashSet<Coordinates> = HashSet::new();
+    for (line_number, line) in numbered_lines(input) {
+        let sides = line.split(",")
+                        .map(|s| parse::number::<i32>(line_number, line, s))
+                        .collect::<std::result::Result<Vec<i32>, ParseError>>()?;
+
+        let &[x, y, z] = sides.as_slice() else {
+            return Err(Box::new(ParseError::new(line_number, line, line, "a cube like '2,2,2'")));
+        };
+        let cube_coordinates = Coordinates::new(x, y, z);
+        cubes.insert(cube_coordinates);
+    }
+
+    Ok(cubes)
+}
\ No newline at end of file
@@ -0,0 +1,26 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::NotEnoughMinerals;
+
+/// `size` blueprints with robot costs in the ranges of the real input.
+impl Generate for NotEnoughMinerals {
+    const DEFAULT_SIZE: usize = 3;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for identifier in 1..=size.max(1) {
+            input.push_str(&format!(
+                "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+                identifier,
+                rng.between(2..=4),
+                rng.between(2..=4),
+                rng.between(2..=4),
+                rng.between(5..=20),
+                rng.between(2..=4),
+                rng.between(7..=20),
+            ));
+        }
+
+        return input;
+    }
+}
@@ -0,0 +1,314 @@
+mod generate;
+
+use std::collections::HashSet;
+
+use aoc_core::parse::{self, numbered_lines};
+use aoc_core::{ParseError, Puzzle, Result};
+use regex::Regex;
+
+#[derive(PartialEq, Eq, Hash, Debug)]
+pub struct Blueprint {
+    name: String,
+    identifier: u32,
+    ore_robot_cost: u32,
+    clay_robot_cost: u32,
+    obisidan_robot_cost: (u32, u32),
+    geode_robot_cost: (u32, u32),
+}
+
+impl Blueprint {
+    fn build(name: String, identifier: u32,) -> Blueprint {
+        Blueprint { 
+            name,
+            identifier,
+            ore_robot_cost: 0, 
+            clay_robot_cost: 0, 
+            obisidan_robot_cost: (0, 0), 
+            geode_robot_cost: (0, 0) 
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    inventory: Inventory,
+    minutes: u32
+}
+
+impl State {
+    fn init(minutes: u32) -> State {
+        State {
+            inventory: Inventory::init(),
+            minutes
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Inventory {
+    ore_robots: u32,
+    clay_robots: u32,
+    obsidian_robots: u32,
+    geode_robots: u32,
+
+    ore_count: u32,
+    clay_count: u32,
+    obsidian_count: u32,
+    geode_count: u32
+}
+
+impl Inventory {
+    fn init() -> Inventory {
+        Inventory { 
+            ore_robots: 1,
+            clay_robots: 0, 
+            obsidian_robots: 0, 
+            geode_robots: 0, 
+            ore_count: 0, 
+            clay_count: 0, 
+            obsidian_count: 0, 
+            geode_count: 0
+        }
+    }
+
+    fn remove_ore(&mut self, count: u32) {
+        if count > self.ore_count {
+            panic!("Ore count cannot be negative")
+        }
+
+        self.ore_count -= count;
+    }
+
+    fn remove_clay(&mut self, count: u32) {
+        if count > self.clay_count {
+            panic!("Clay count cannot be negative")
+        }
+
+        self.clay_count -= count;
+    }
+
+    fn remove_obsidian(&mut self, count: u32) {
+        if count > self.obsidian_count {
+            panic!("Obsidian count cannot be negative")
+        }
+
+        self.obsidian_count -= count;
+    }
+
+    fn add_ore_robot(&mut self) {
+        self.ore_robots += 1;
+    }
+
+    fn add_clay_robot(&mut self) {
+        self.clay_robots += 1;
+    }
+
+    fn add_obsidian_robot(&mut self) {
+        self.obsidian_robots += 1;
+    }
+
+    fn add_geode_robot(&mut self) {
+        self.geode_robots += 1;
+    }
+
+    fn update_inv(&mut self) {
+        self.ore_count += self.ore_robots;
+        self.clay_count += self.clay_robots;            
+        self.obsidian_count += self.obsidian_robots;            
+        self.geode_count += self.geode_robots;            
+    }
+
+    fn was_able_to_buy_robot_before(&self, robot_cost: u32) -> bool {
+        let ore_count = self.ore_count as i32;
+        let ore_robots_count = self.ore_robots as i32;
+        let robot_cost = robot_cost as i32;
+
+        ore_count - ore_robots_count >= robot_cost
+    }
+
+    fn was_able_to_buy_obsidian_robot_before(&self, (ore_cost, clay_cost): (u32, u32)) -> bool {
+        let ore_count = self.ore_count as i32;
+        let ore_robots_count = self.ore_robots as i32;
+        let ore_cost = ore_cost as i32;
+
+        let clay_count = self.clay_count as i32;
+        let clay_robots_count = self.clay_robots as i32;
+        let clay_robot_cost = clay_cost as i32;
+
+        ore_count - ore_robots_count >= ore_cost && 
+        clay_count - clay_robots_count >= clay_robot_cost
+    }
+}
+
+pub struct NotEnoughMinerals;
+
+impl Puzzle for NotEnoughMinerals {
+    const DAY: u8 = 19;
+
+    type Parsed = HashSet<Blueprint>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(blueprints: &Self::Parsed) -> Result<Self::Answer1> {
+        part1(blueprints)
+    }
+
+    fn part2(blueprints: &Self::Parsed) -> Result<Self::Answer2> {
+        part2(blueprints)
+    }
+}
+
+fn part1(blueprints: &HashSet<Blueprint>) -> Result<u32> {
+    let mut collected_geodes = HashSet::new();
+
+    for blueprint in blueprints {
+        let state = State::init(24);
+        let geodes_count = take_action(state, blueprint);
+        
+        _ = collected_geodes.insert((blueprint.identifier, geodes_count))
+    }
+
+    let mut quality_sum: u32 = 0;
+    for (id, geodes) in collected_geodes {
+        quality_sum += id * geodes;    
+    }
+
+    Ok(quality_sum)
+}
+
+fn part2(blueprints: &HashSet<Blueprint>) -> Result<u32> {
+    let mut collected_geodes = HashSet::new();
+
+    for blueprint in blueprints {
+        let state = State::init(32);
+        let geodes_count = take_action(state, blueprint);
+        
+        _ = collected_geodes.insert((blueprint.identifier, geodes_count))
+    }
+
+    let mut geodes_multiply: u32 = 1;
+    for (_, geodes) in collected_geodes {
+        geodes_multiply *= geodes;    
+    }
+
+    Ok(geodes_multiply)
+}
+
+fn take_action(mut state: State, blueprint: &Blueprint) -> u32 {
+    let mut collected_geodes = HashSet::new();
+    let mut can_buy_geode_robot = false;
+
+    if state.minutes == 0 {
+        return state.inventory.geode_count;
+    }
+    state.minutes -= 1;
+
+    if state.inventory.ore_count >= blueprint.geode_robot_cost.0 &&
+       state.inventory.obsidian_count >= blueprint.geode_robot_cost.1 {
+        can_buy_geode_robot = true;
+        let mut new_state = state.clone();
+
+        new_state.inventory.remove_ore(blueprint.geode_robot_cost.0);
+        new_state.inventory.remove_obsidian(blueprint.geode_robot_cost.1);
+        new_state.inventory.update_inv();
+        new_state.inventory.add_geode_robot();
+
+        collected_geodes.insert(take_action(new_state, blueprint));
+    }
+    if state.inventory.ore_count >= blueprint.ore_robot_cost && 
+       !state.inventory.was_able_to_buy_robot_before(blueprint.ore_robot_cost) &&
+       !can_buy_geode_robot &&
+       state.inventory.ore_count < state.minutes {
+        let mut new_state = state.clone();
+
+        new_state.inventory.remove_ore(blueprint.ore_robot_cost);
+        new_state.inventory.update_inv();
+        new_state.inventory.add_ore_robot();
+
+        collected_geodes.insert(take_action(new_state, blueprint));
+    }
+    if state.inventory.ore_count >= blueprint.clay_robot_cost && 
+       !state.inventory.was_able_to_buy_robot_before(blueprint.clay_robot_cost) &&
+       !can_buy_geode_robot &&
+       state.inventory.ore_count < state.minutes {
+        let mut new_state = state.clone();
+
+        new_state.inventory.remove_ore(blueprint.clay_robot_cost);
+        new_state.inventory.update_inv();
+        new_state.inventory.add_clay_robot();
+
+        collected_geodes.insert(take_action(new_state, blueprint));
+    }
+    if state.inventory.ore_count >= blueprint.obisidan_robot_cost.0 &&
+       state.inventory.clay_count >= blueprint.obisidan_robot_cost.1 && 
+       !state.inventory.was_able_to_buy_obsidian_robot_before(blueprint.obisidan_robot_cost) &&
+       !can_buy_geode_robot {
+        let mut new_state = state.clone();
+
+        new_state.inventory.remove_ore(blueprint.obisidan_robot_cost.0);
+        new_state.inventory.remove_clay(blueprint.obisidan_robot_cost.1);
+        new_state.inventory.update_inv();
+        new_state.inventory.add_obsidian_robot();
+
+        collected_geodes.insert(take_action(new_state, blueprint));
+    }
+
+    if !can_buy_geode_robot {
+        state.inventory.update_inv();
+        _ = collected_geodes.insert(take_action(state, blueprint));
+    }
+
+    return *collected_geodes.iter().max().expect("Geode max count should be present");
+}
+
+fn parse(input: &str) -> Result<HashSet<Blueprint>> {
+    let blueprint_re = Regex::new(r"Blueprint (\d+):")?;
+    let ore_re = Regex::new(r"Each ore robot costs (\d+) ore\.")?;
+    let clay_re = Regex::new(r"Each clay robot costs (\d+) ore\.")?;
+    let obsidian_re = Regex::new(r"Each obsidian robot costs (\d+) ore and (\d+) clay\.")?;
+    let geode_re = Regex::new(r"Each geode robot costs (\d+) ore and (\d+) obsidian\.")?;
+
+    let mut blueprints: Vec<Blueprint> = Vec::new();
+    for (line_number, i) in numbered_lines(input) {
+        let costs = |re: &Regex, robot: &str, expected: &str| -> std::result::Result<Vec<u32>, ParseError> {
+            let start = i.find(&format!("Each {} robot", robot)).unwrap_or(0);
+            let captures = re.captures(i).ok_or_else(|| ParseError::new(line_number, i, &i[start..], expected))?;
+            captures.iter().skip(1).map(|c| parse::number(line_number, i, c.unwrap().as_str())).collect()
+        };
+
+        if i.contains("Blueprint") {
+            let captures = blueprint_re.captures(i)
+                .ok_or_else(|| ParseError::new(line_number, i, i, "a header like 'Blueprint 1:'"))?;
+            let name = captures.get(0).unwrap().as_str().trim_end_matches(':');
+            let identifier = parse::number::<u32>(line_number, i, captures.get(1).unwrap().as_str())?;
+
+            let blueprint = Blueprint::build(String::from(name), identifier);
+            blueprints.push(blueprint);
+        } else if !i.trim().is_empty() && blueprints.is_empty() {
+            return Err(Box::new(ParseError::new(line_number, i, i.trim(), "a header like 'Blueprint 1:'")));
+        }
+
+        let Some(blueprint) = blueprints.last_mut() else { continue };
+        if i.contains("ore robot costs") {
+            blueprint.ore_robot_cost = costs(&ore_re, "ore", "'Each ore robot costs 4 ore.'")?[0];
+        }
+        if i.contains("clay robot costs") {
+            blueprint.clay_robot_cost = costs(&clay_re, "clay", "'Each clay robot costs 2 ore.'")?[0];
+        }
+        if i.contains("obsidian robot costs") {
+            let cost = costs(&obsidian_re, "obsidian", "'Each obsidian robot costs 3 ore and 14 clay.'")?;
+            blueprint.obisidan_robot_cost = (cost[0], cost[1]);
+        }
+        if i.contains("geode robot costs") {
+            let cost = costs(&geode_re, "geode", "'Each geode robot costs 2 ore and 7 obsidian.'")?;
+            blueprint.geode_robot_cost = (cost[0], cost[1]);
+        }
+    }
+
+    Ok(HashSet::from_iter(blueprints))
+}
+
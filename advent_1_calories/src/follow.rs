@@ -0,0 +1,111 @@
+use std::cmp::Reverse;
+
+use aoc_core::parse::{self, ParseError};
+
+use crate::RankedElf;
+
+/// Running totals of an inventory that is still being written, fed the
+/// appended bytes as they come. Only complete lines are read; the rest waits
+/// for the next chunk.
+///
+/// An elf's total only grows while its items come in, so the leaderboard is
+/// kept up to date from the elf being read alone, without ranking every elf
+/// again.
+pub struct Tally {
+    totals: Vec<u64>,
+    items: usize,
+    lines: usize,
+    pending: Vec<u8>,
+    top_count: usize,
+    /// The `top_count` heaviest carriers so far, ranked as by [`crate::top_n`].
+    leaderboard: Vec<RankedElf>,
+}
+
+impl Tally {
+    pub fn new(top_count: usize) -> Tally {
+        Tally { totals: Vec::new(), items: 0, lines: 0, pending: Vec::new(), top_count, leaderboard: Vec::new() }
+    }
+
+    /// Reads the complete lines of `bytes`, after whatever was left over
+    /// from the previous chunk. A line that is not a number is skipped and
+    /// returned as an error, with its line number in the whole input.
+    pub fn push(&mut self, bytes: &[u8]) -> Vec<ParseError> {
+        self.pending.extend_from_slice(bytes);
+        let Some(end) = self.pending.iter().rposition(|&byte| byte == b'\n') else {
+            return Vec::new();
+        };
+
+        let complete: Vec<u8> = self.pending.drain(..=end).collect();
+        let mut errors = Vec::new();
+        for line in String::from_utf8_lossy(&complete).lines() {
+            self.lines += 1;
+            if let Err(e) = self.read_line(line) {
+                errors.push(e);
+            }
+        }
+
+        return errors;
+    }
+
+    /// Reads what is left after the last line break, for an input that has
+    /// ended without one.
+    pub fn finish(&mut self) -> Vec<ParseError> {
+        if self.pending.is_empty() {
+            return Vec::new();
+        }
+        return self.push(b"\n");
+    }
+
+    /// Same rules as the puzzle's parser: a blank line starts a new elf.
+    fn read_line(&mut self, line: &str) -> Result<(), ParseError> {
+        if line.is_empty() || self.totals.is_empty() {
+            self.totals.push(0);
+        }
+        if line.is_empty() {
+            return Ok(());
+        }
+
+        let calorie_count: u32 = parse::number(self.lines, line, line)?;
+        let index = self.totals.len() - 1;
+        self.totals[index] += calorie_count as u64;
+        self.items += 1;
+        self.rank(RankedElf { index, calories: self.totals[index] });
+        return Ok(());
+    }
+
+    /// Puts an elf whose total grew on the leaderboard, or moves it up.
+    fn rank(&mut self, elf: RankedElf) {
+        match self.leaderboard.iter_mut().find(|ranked| ranked.index == elf.index) {
+            Some(ranked) => ranked.calories = elf.calories,
+            None => self.leaderboard.push(elf),
+        }
+
+        self.leaderboard.sort_by_key(|ranked| (Reverse(ranked.calories), ranked.index));
+        self.leaderboard.truncate(self.top_count);
+    }
+
+    /// The heaviest carriers so far, most first; fewer than the top count
+    /// while not enough elves carry food.
+    pub fn leaderboard(&self) -> &[RankedElf] {
+        &self.leaderboard
+    }
+
+    /// Total calories of every elf read so far, in input order.
+    pub fn totals(&self) -> &[u64] {
+        &self.totals
+    }
+
+    /// Carriers kept on the leaderboard.
+    pub fn top_count(&self) -> usize {
+        self.top_count
+    }
+
+    pub fn items(&self) -> usize {
+        self.items
+    }
+
+    /// Complete lines read so far.
+    pub fn lines(&self) -> usize {
+        self.lines
+    }
+}
@@ -0,0 +1,17 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::CalorieCounting;
+
+/// `size` elves carrying 1 to 15 snacks each, at least the 3 part 2 adds up.
+impl Generate for CalorieCounting {
+    const DEFAULT_SIZE: usize = 250;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let elves: Vec<String> = (0..size.max(3)).map(|_| {
+            let snacks: Vec<String> = (0..rng.between(1..=15)).map(|_| rng.between(1000..=60000).to_string()).collect();
+            snacks.join("\n")
+        }).collect();
+
+        return elves.join("\n\n") + "\n";
+    }
+}
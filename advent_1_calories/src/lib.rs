@@ -0,0 +1,155 @@
+pub mod follow;
+mod generate;
+pub mod plan;
+pub mod report;
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use aoc_core::parse::{self, numbered_lines};
+use aoc_core::{verbose, DayConfig, Puzzle, Result};
+
+/// Elves whose calories are added up in part 2.
+pub const TOP_COUNT: usize = 3;
+
+pub struct Inventories {
+    elves: Vec<Elf>,
+    top_count: usize,
+}
+
+impl Inventories {
+    /// Every elf, in input order.
+    pub fn elves(&self) -> &[Elf] {
+        &self.elves
+    }
+
+    /// Total calories carried by every elf, in input order.
+    pub fn calories(&self) -> Vec<u64> {
+        self.elves.iter().map(Elf::calories).collect()
+    }
+}
+
+/// One elf's inventory: the calories of every food item it carries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    /// Position of the inventory in the input, from 0.
+    pub index: usize,
+    pub items: Vec<u32>,
+}
+
+impl Elf {
+    /// Calories of all the elf's items together, in `u64` so that many large
+    /// items cannot overflow the sum.
+    pub fn calories(&self) -> u64 {
+        self.items.iter().map(|&calories| calories as u64).sum()
+    }
+}
+
+pub struct CalorieCounting;
+
+impl Puzzle for CalorieCounting {
+    const DAY: u8 = 1;
+
+    type Parsed = Inventories;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(inventories: &Self::Parsed) -> Result<Self::Answer1> {
+        part1(&inventories.calories())
+    }
+
+    fn part2(inventories: &Self::Parsed) -> Result<Self::Answer2> {
+        part2(&inventories.calories(), inventories.top_count)
+    }
+
+    fn configure(inventories: &mut Self::Parsed, config: &DayConfig) -> Result<()> {
+        inventories.top_count = config.get_at_least("top", TOP_COUNT, 1)?;
+        Ok(())
+    }
+}
+
+/// Every elf's items, in input order. Items are one per line, with a blank
+/// line between elves; a line that is not a number fails with its position.
+fn parse(input: &str) -> Result<Inventories> {
+    let mut elves: Vec<Elf> = Vec::new();
+    for (line_number, line) in numbered_lines(input) {
+        if line.is_empty() || elves.is_empty() {
+            elves.push(Elf { index: elves.len(), items: Vec::new() });
+        }
+        
+        if !line.is_empty() {
+            let calorie_count: u32 = parse::number(line_number, line, line)?;
+            //Certain to unwrap, because an elf is added above if list is empty
+            let elf = elves.last_mut().unwrap();
+            elf.items.push(calorie_count);
+        }
+    }
+
+    return Ok(Inventories { elves, top_count: TOP_COUNT });
+}
+
+fn part1(calories: &[u64]) -> Result<u64> {
+    let top = top_n(calories, 1)?;
+    return Ok(top[0].calories);
+}
+
+fn part2(calories: &[u64], top_count: usize) -> Result<u64> {
+    let top = top_n(calories, top_count)?;
+    for (rank, elf) in top.iter().enumerate() {
+        verbose!("{}. elf {} carries {} calories", rank + 1, elf.index + 1, elf.calories);
+    }
+
+    return Ok(top.iter().map(|elf| elf.calories).sum());
+}
+
+/// An elf picked by [`top_n`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RankedElf {
+    /// Position of the elf's inventory in the input, from 0.
+    pub index: usize,
+    pub calories: u64,
+}
+
+/// The `n` elves carrying the most calories, most first; between elves
+/// carrying as much, the one listed first ranks higher.
+///
+/// Only the best `n` seen so far are kept, in a heap, so this is
+/// O(len × log n) rather than a full sort.
+pub fn top_n(calories: &[u64], n: usize) -> std::result::Result<Vec<RankedElf>, NotEnoughElves> {
+    if calories.len() < n {
+        return Err(NotEnoughElves { wanted: n, found: calories.len() });
+    }
+
+    // Min-heap on (calories, later index first), so the weakest of the
+    // kept elves is the one dropped
+    let mut heap = BinaryHeap::with_capacity(n + 1);
+    for (index, &calories) in calories.iter().enumerate() {
+        heap.push(Reverse((calories, Reverse(index))));
+        if heap.len() > n {
+            heap.pop();
+        }
+    }
+
+    let ranked = heap.into_sorted_vec().into_iter()
+        .map(|Reverse((calories, Reverse(index)))| RankedElf { index, calories })
+        .collect();
+    return Ok(ranked);
+}
+
+#[derive(Debug)]
+pub struct NotEnoughElves {
+    pub wanted: usize,
+    pub found: usize,
+}
+
+impl std::error::Error for NotEnoughElves {}
+
+impl std::fmt::Display for NotEnoughElves {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The top {} elves were asked for, but only {} carry food", self.wanted, self.found)
+    }
+}
@@ -1,34 +1,203 @@
-use std::fs;
-     
-pub type Error = Box<dyn std::error::Error>;
-pub type Result<T> = std::result::Result<T, Error>;
-
-fn main() -> Result<()> {
-    let input = fs::read_to_string("./input.txt")?;
-    
-    let mut calories: Vec<u32> = Vec::new();
-    for line in input.lines() {
-        if line.is_empty() || calories.len() == 0 {
-            calories.push(0);
+use std::env;
+use std::fs::{self, File, Metadata};
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::thread;
+use std::time::Duration;
+
+use advent_1_calories::follow::Tally;
+use advent_1_calories::plan::plan;
+use advent_1_calories::report::{Report, ReportFormat};
+use advent_1_calories::{CalorieCounting, Inventories, RankedElf, TOP_COUNT};
+use aoc_core::input::{self, DEFAULT_INPUT};
+use aoc_core::{cli, parse, read_input, Config, ParseError, Puzzle, Result};
+
+/// How often a followed file is checked for appended lines.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("report") => report(&args[1..]),
+        Some("plan") => balance(&args[1..]),
+        Some("follow") => follow(&args[1..]),
+        _ => {
+            cli::run_day::<CalorieCounting>(default_input(), [
+                "Most calories carried",
+                "Calories carried by the top elves",
+            ]);
+            Ok(())
         }
-        
-        if !line.is_empty() {
-            if let Ok(calorie_count) = line.parse::<u32>() {
-                //Certain to unwrap, because in line 12 item is added if list is empty
-                let calories_bag = calories.last_mut().unwrap();
-                *calories_bag = *calories_bag + calorie_count;
-            } else {
-                println!("Bad input, not number {}", line);
-                continue;
-            }
+    };
+
+    if let Err(e) = result {
+        eprintln!("{}", parse::report(&e));
+        process::exit(1);
+    }
+}
+
+fn default_input() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(DEFAULT_INPUT)
+}
+
+/// `report [INPUT] [--format text|csv|json]`: statistics over the elves'
+/// loads instead of the puzzle answers.
+fn report(args: &[String]) -> Result<()> {
+    let (inventories, format) = read_inventories(args)?;
+    let report = Report::new(inventories.elves())?;
+
+    match format {
+        ReportFormat::Text => print!("{}", report),
+        ReportFormat::Csv => print!("{}", report.to_csv()),
+        ReportFormat::Json => println!("{}", report.to_json().pretty()),
+    }
 
+    Ok(())
+}
+
+/// `plan [INPUT] [--format text|csv|json]`: the items to hand around so the
+/// heaviest load is as light as possible. Refining a large inventory costs
+/// O(items²) per step, so it stops after
+/// [`advent_1_calories::plan::IMPROVE_PAIR_LIMIT`] item pairs with the best
+/// plan found.
+fn balance(args: &[String]) -> Result<()> {
+    let (inventories, format) = read_inventories(args)?;
+    let plan = plan(inventories.elves())?;
+
+    match format {
+        ReportFormat::Text => print!("{}", plan),
+        ReportFormat::Csv => print!("{}", plan.to_csv()),
+        ReportFormat::Json => println!("{}", plan.to_json().pretty()),
+    }
+
+    Ok(())
+}
+
+/// Parsed input and output format of the `report` and `plan` commands.
+fn read_inventories(args: &[String]) -> Result<(Inventories, ReportFormat)> {
+    let mut format = ReportFormat::default();
+    let mut options = args.iter();
+    while let Some(arg) = options.next() {
+        if arg == "--format" {
+            format = options.next().ok_or("Missing value for --format")?.parse()?;
         }
     }
 
-    calories.sort();
-    calories.reverse();
-    let calories_top_3 = calories[..3].to_vec();
-    let sum: u32 = calories_top_3.iter().sum();
-    println!("{}", sum);
-    return Ok(());
+    let path = cli::input_path(args.iter().cloned())?.unwrap_or_else(default_input);
+    let input = read_input(&path)?;
+    let inventories = CalorieCounting::parse(&input).map_err(|e| parse::in_file(e, &path))?;
+    Ok((inventories, format))
+}
+
+/// `follow [INPUT]`: reads the inventory as lines are appended to it, like
+/// `tail -f`, and prints the leaderboard of the `day1.top` heaviest carriers
+/// whenever it changes. Only the appended bytes are read; a file that
+/// shrinks is read again from the start, and a file replaced by another one
+/// at the same path (log rotation) is followed from the start of the new
+/// one. Runs until interrupted, or until the end of stdin.
+fn follow(args: &[String]) -> Result<()> {
+    let config = Config::from_args(args.iter().cloned())?;
+    let day_config = config.day(CalorieCounting::DAY);
+    let top_count = day_config.get_at_least("top", TOP_COUNT, 1)?;
+    day_config.check_unused()?;
+
+    let path = cli::input_path(args.iter().cloned())?.unwrap_or_else(default_input);
+    let mut tally = Tally::new(top_count);
+    let mut shown = None;
+
+    let result = match input::is_stdin(&path) {
+        true => follow_stdin(&mut tally, &path, &mut shown),
+        false => follow_file(&mut tally, &path, &mut shown),
+    };
+    // A last line without a line break still counts
+    let errors = tally.finish();
+    update(&tally, errors, &path, &mut shown);
+    return result;
+}
+
+fn follow_stdin(tally: &mut Tally, path: &Path, shown: &mut Option<Vec<RankedElf>>) -> Result<()> {
+    let mut buffer = [0; 8192];
+    loop {
+        let read = io::stdin().read(&mut buffer)?;
+        if read == 0 {
+            return Ok(());
+        }
+        let errors = tally.push(&buffer[..read]);
+        update(tally, errors, path, shown);
+    }
+}
+
+/// Polls the file at `path`, starting over on a new tally whenever it is
+/// truncated or replaced. The tail of the old file is read before switching.
+fn follow_file(tally: &mut Tally, path: &Path, shown: &mut Option<Vec<RankedElf>>) -> Result<()> {
+    let open = || File::open(path).map_err(|e| format!("Cannot follow {}: {}", path.display(), e));
+    let mut file = open()?;
+    let mut offset = 0;
+    let mut chunk = Vec::new();
+    loop {
+        chunk.clear();
+        offset += file.read_to_end(&mut chunk)? as u64;
+        let errors = tally.push(&chunk);
+        update(tally, errors, path, shown);
+
+        let restart = match fs::metadata(path) {
+            Ok(metadata) if !same_file(&metadata, &file.metadata()?) => {
+                println!("==> {} replaced, reading the new file <==", input::display_name(path));
+                file = open()?;
+                true
+            }
+            Ok(metadata) if metadata.len() < offset => {
+                println!("==> {} truncated, reading it again <==", input::display_name(path));
+                file.seek(SeekFrom::Start(0))?;
+                true
+            }
+            // Moved away and not created again yet
+            Err(e) if e.kind() == io::ErrorKind::NotFound => false,
+            Err(e) => return Err(format!("Cannot follow {}: {}", path.display(), e).into()),
+            Ok(_) => false,
+        };
+        if restart {
+            let errors = tally.finish();
+            update(tally, errors, path, shown);
+            *tally = Tally::new(tally.top_count());
+            *shown = None;
+            offset = 0;
+            continue;
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Whether both are the same file on disk, rather than one that replaced the
+/// other at the same path.
+#[cfg(unix)]
+fn same_file(a: &Metadata, b: &Metadata) -> bool {
+    use std::os::unix::fs::MetadataExt;
+    a.dev() == b.dev() && a.ino() == b.ino()
+}
+
+#[cfg(not(unix))]
+fn same_file(a: &Metadata, b: &Metadata) -> bool {
+    a.created().ok() == b.created().ok()
+}
+
+/// Reports the lines the tally skipped, then prints the leaderboard when it
+/// differs from the one `shown` last.
+fn update(tally: &Tally, errors: Vec<ParseError>, path: &Path, shown: &mut Option<Vec<RankedElf>>) {
+    for error in errors {
+        eprintln!("{}", parse::report(&parse::in_file(error.into(), path)));
+    }
+    if shown.as_deref() == Some(tally.leaderboard()) {
+        return;
+    }
+
+    println!("Top {} of {} elves ({} items), {} calories:", tally.leaderboard().len(), tally.totals().len(), tally.items(),
+        tally.leaderboard().iter().map(|elf| elf.calories).sum::<u64>());
+    for (rank, elf) in tally.leaderboard().iter().enumerate() {
+        println!("{}. elf {} carries {} calories", rank + 1, elf.index + 1, elf.calories);
+    }
+    println!();
+    *shown = Some(tally.leaderboard().to_vec());
 }
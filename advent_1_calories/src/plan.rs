@@ -0,0 +1,310 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use aoc_core::json::Json;
+
+use crate::{Elf, NotEnoughElves};
+
+/// Most items searched exhaustively; larger inventories are balanced with
+/// the greedy heuristic and its improvement pass.
+pub const EXACT_ITEM_LIMIT: usize = 16;
+/// Most pairs of items one improvement pass compares. Each step pairs the
+/// heaviest group's items with every item, so without a cap a large
+/// inventory could take minutes to refine; this keeps it around a second.
+pub const IMPROVE_PAIR_LIMIT: usize = 50_000_000;
+
+/// Reassignment of whole items between the elves that makes the heaviest
+/// load as light as possible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    /// Every elf's calories after the moves, in input order.
+    pub loads: Vec<u64>,
+    pub moves: Vec<Move>,
+    /// No plan can make the heaviest load lighter than this: the average
+    /// load, or the heaviest single item.
+    pub lower_bound: u64,
+    /// Whether the heaviest load is known to be the lightest possible,
+    /// either from the exact search or by reaching [`Plan::lower_bound`].
+    pub optimal: bool,
+}
+
+/// One item handed over from an elf to another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    /// Elf giving the item away, from 0.
+    pub from: usize,
+    /// Position of the item in the giving elf's inventory, from 0.
+    pub item: usize,
+    pub calories: u32,
+    /// Elf receiving the item, from 0.
+    pub to: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Item {
+    /// Owner before the plan, as a position in the elves' slice.
+    elf: usize,
+    position: usize,
+    calories: u64,
+}
+
+/// Balances the elves' loads by handing whole items around, leaving items
+/// with the elf already carrying them where the balance allows.
+///
+/// Two heuristic plans are compared: items dealt heaviest first to the
+/// lightest elf (longest processing time), and the current loads, both then
+/// refined by moves and swaps out of the heaviest load while they lighten it.
+/// The current loads win ties, as they hand far fewer items around. Up to
+/// [`EXACT_ITEM_LIMIT`] items, every assignment is then searched (branch and
+/// bound) for a lighter heaviest load. Loads are only rebalanced when that
+/// lowers the heaviest one.
+pub fn plan(elves: &[Elf]) -> Result<Plan, NotEnoughElves> {
+    if elves.is_empty() {
+        return Err(NotEnoughElves { wanted: 1, found: 0 });
+    }
+
+    let mut items: Vec<Item> = elves.iter().enumerate()
+        .flat_map(|(elf, inventory)| inventory.items.iter().enumerate()
+            .map(move |(position, &calories)| Item { elf, position, calories: calories as u64 }))
+        .collect();
+    items.sort_by_key(|item| std::cmp::Reverse(item.calories));
+
+    let total: u64 = items.iter().map(|item| item.calories).sum();
+    let heaviest_item = items.first().map_or(0, |item| item.calories);
+    let lower_bound = total.div_ceil(elves.len() as u64).max(heaviest_item);
+
+    let original: Vec<usize> = items.iter().map(|item| item.elf).collect();
+    let original_max = elves.iter().map(Elf::calories).max().unwrap_or(0);
+
+    let mut current = original.clone();
+    let mut current_loads: Vec<u64> = elves.iter().map(Elf::calories).collect();
+    improve(&items, &mut current, &mut current_loads);
+    let current_max = current_loads.iter().copied().max().unwrap_or(0);
+
+    let (mut assignment, mut max_load) = match greedy(&items, elves.len()) {
+        (dealt, dealt_max) if dealt_max < current_max => (dealt, dealt_max),
+        _ => (current, current_max),
+    };
+    let mut optimal = max_load == lower_bound;
+    if items.len() <= EXACT_ITEM_LIMIT && !optimal {
+        let mut search = Search::new(&items, elves.len(), lower_bound, max_load);
+        search.branch(0);
+        if let Some(best) = search.best_assignment {
+            assignment = best;
+            max_load = search.best;
+        }
+        optimal = true;
+    }
+
+    // Nothing to gain from moving items around
+    if max_load >= original_max {
+        assignment = original;
+        optimal = optimal || original_max == lower_bound;
+    }
+
+    let elf_of_group = match_groups(&items, &assignment, elves.len());
+    let mut loads = vec![0; elves.len()];
+    let mut moves = Vec::new();
+    for (item, &group) in items.iter().zip(&assignment) {
+        let to = elf_of_group[group];
+        loads[to] += item.calories;
+        if to != item.elf {
+            let calories = elves[item.elf].items[item.position];
+            moves.push(Move { from: elves[item.elf].index, item: item.position, calories, to: elves[to].index });
+        }
+    }
+    moves.sort_by_key(|m| (m.from, m.item));
+
+    return Ok(Plan { loads, moves, lower_bound, optimal });
+}
+
+/// Longest processing time: every item, heaviest first, goes to the group
+/// carrying the least so far. Then refined by [`improve`].
+fn greedy(items: &[Item], groups: usize) -> (Vec<usize>, u64) {
+    let mut loads = vec![0; groups];
+    let mut assignment = Vec::with_capacity(items.len());
+    for item in items {
+        let lightest = (0..groups).min_by_key(|&group| loads[group]).unwrap();
+        loads[lightest] += item.calories;
+        assignment.push(lightest);
+    }
+
+    improve(items, &mut assignment, &mut loads);
+    return (assignment, loads.iter().copied().max().unwrap_or(0));
+}
+
+/// Moves an item out of the heaviest group to the lightest one, or swaps it
+/// for a lighter item of another group, as long as that lightens the
+/// heaviest group without making the other one as heavy. Every step lowers
+/// the sum of the squared loads, so this ends.
+///
+/// A step costs O(heaviest group's items × items), and nothing else bounds
+/// the number of steps, so the pass stops with the balance reached so far
+/// once it has compared [`IMPROVE_PAIR_LIMIT`] pairs.
+fn improve(items: &[Item], assignment: &mut [usize], loads: &mut [u64]) {
+    let mut pairs = 0;
+    while pairs < IMPROVE_PAIR_LIMIT {
+        let heaviest = (0..loads.len()).max_by_key(|&group| loads[group]).unwrap();
+        let lightest = (0..loads.len()).min_by_key(|&group| loads[group]).unwrap();
+        let in_heaviest: Vec<usize> = (0..items.len()).filter(|&i| assignment[i] == heaviest).collect();
+        pairs += (in_heaviest.len() + 1) * items.len();
+
+        // Candidates as (heavier of the two groups afterwards, item swapped
+        // with, item out of the heaviest group): on a tie, moving one item
+        // beats swapping two
+        let gap = loads[heaviest] - loads[lightest];
+        let best_move = in_heaviest.iter()
+            .filter(|&&i| items[i].calories > 0 && items[i].calories < gap)
+            .map(|&i| ((loads[heaviest] - items[i].calories).max(loads[lightest] + items[i].calories), None, i))
+            .min();
+        let best_swap = in_heaviest.iter()
+            .flat_map(|&i| (0..items.len()).map(move |j| (i, j)))
+            .filter(|&(i, j)| {
+                let other = assignment[j];
+                other != heaviest && items[j].calories < items[i].calories
+                    && items[i].calories - items[j].calories < loads[heaviest] - loads[other]
+            })
+            .map(|(i, j)| {
+                let change = items[i].calories - items[j].calories;
+                ((loads[heaviest] - change).max(loads[assignment[j]] + change), Some(j), i)
+            })
+            .min();
+
+        let Some((_, swap, i)) = best_move.into_iter().chain(best_swap).min() else {
+            return;
+        };
+        match swap {
+            None => {
+                loads[heaviest] -= items[i].calories;
+                loads[lightest] += items[i].calories;
+                assignment[i] = lightest;
+            }
+            Some(j) => {
+                let other = assignment[j];
+                let change = items[i].calories - items[j].calories;
+                loads[heaviest] -= change;
+                loads[other] += change;
+                assignment.swap(i, j);
+            }
+        }
+    }
+}
+
+/// Branch and bound over every assignment of the items, heaviest first, to
+/// groups, looking for a heaviest load below `best`.
+struct Search<'a> {
+    items: &'a [Item],
+    loads: Vec<u64>,
+    assignment: Vec<usize>,
+    lower_bound: u64,
+    best: u64,
+    best_assignment: Option<Vec<usize>>,
+}
+
+impl<'a> Search<'a> {
+    fn new(items: &'a [Item], groups: usize, lower_bound: u64, best: u64) -> Search<'a> {
+        Search { items, loads: vec![0; groups], assignment: Vec::with_capacity(items.len()), lower_bound, best, best_assignment: None }
+    }
+
+    /// Places item `i` and the ones after it; true once the lower bound is
+    /// reached and nothing better can be found.
+    fn branch(&mut self, i: usize) -> bool {
+        let Some(item) = self.items.get(i) else {
+            self.best = self.loads.iter().copied().max().unwrap_or(0);
+            self.best_assignment = Some(self.assignment.clone());
+            return self.best == self.lower_bound;
+        };
+
+        for group in 0..self.loads.len() {
+            let load = self.loads[group];
+            // Groups carrying as much are interchangeable, only try the first
+            if load + item.calories >= self.best || self.loads[..group].contains(&load) {
+                continue;
+            }
+
+            self.loads[group] += item.calories;
+            self.assignment.push(group);
+            let done = self.branch(i + 1);
+            self.assignment.pop();
+            self.loads[group] -= item.calories;
+            if done {
+                return true;
+            }
+        }
+
+        return false;
+    }
+}
+
+/// Which elf carries each group, matched greedily: the group and elf sharing
+/// the most items are paired first, then the next pair left, so that few
+/// items change hands. This is not an optimal assignment and may move a few
+/// more items than needed when overlaps compete.
+fn match_groups(items: &[Item], assignment: &[usize], elves: usize) -> Vec<usize> {
+    let mut overlap: HashMap<(usize, usize), usize> = HashMap::new();
+    for (item, &group) in items.iter().zip(assignment) {
+        *overlap.entry((group, item.elf)).or_insert(0) += 1;
+    }
+    let mut pairs: Vec<((usize, usize), usize)> = overlap.into_iter().collect();
+    pairs.sort_by_key(|&((group, elf), count)| (std::cmp::Reverse(count), group, elf));
+
+    let mut elf_of_group = vec![None; elves];
+    let mut taken = vec![false; elves];
+    for ((group, elf), _) in pairs {
+        if elf_of_group[group].is_none() && !taken[elf] {
+            elf_of_group[group] = Some(elf);
+            taken[elf] = true;
+        }
+    }
+
+    let mut free = (0..elves).filter(|&elf| !taken[elf]);
+    return elf_of_group.into_iter()
+        .map(|elf| elf.or_else(|| free.next()).expect("As many groups as elves"))
+        .collect();
+}
+
+impl Plan {
+    /// Heaviest load after the moves.
+    pub fn max_load(&self) -> u64 {
+        self.loads.iter().copied().max().unwrap_or(0)
+    }
+
+    /// One row per move: `from,item,calories,to`, elves and items numbered
+    /// from 1.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("from,item,calories,to\n");
+        for m in &self.moves {
+            csv += &format!("{},{},{},{}\n", m.from + 1, m.item + 1, m.calories, m.to + 1);
+        }
+
+        return csv;
+    }
+
+    /// The balance reached and every move, elves and items numbered from 1.
+    pub fn to_json(&self) -> Json {
+        let moves: Vec<Json> = self.moves.iter()
+            .map(|m| Json::object().field("from", m.from + 1).field("item", m.item + 1).field("calories", m.calories).field("to", m.to + 1))
+            .collect();
+
+        return Json::object()
+            .field("max_load", self.max_load())
+            .field("lower_bound", self.lower_bound)
+            .field("optimal", self.optimal)
+            .field("loads", self.loads.clone())
+            .field("moves", moves);
+    }
+}
+
+/// The balance reached, then the moves one per line.
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let quality = if self.optimal { "optimal" } else { "best found" };
+        writeln!(f, "Heaviest load: {} calories ({}, lower bound {})", self.max_load(), quality, self.lower_bound)?;
+        writeln!(f, "Moves: {}", self.moves.len())?;
+        for m in &self.moves {
+            writeln!(f, "  elf {} gives item {} ({} calories) to elf {}", m.from + 1, m.item + 1, m.calories, m.to + 1)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_core::json::Json;
+
+use crate::{Elf, NotEnoughElves};
+
+/// Percentiles listed in the report, nearest-rank.
+pub const PERCENTILES: [u8; 5] = [10, 25, 50, 75, 90];
+/// Most bars in the histogram of loads.
+pub const HISTOGRAM_BUCKETS: u64 = 10;
+/// Length of the longest histogram bar, in characters.
+const BAR_WIDTH: usize = 40;
+
+/// Statistics over the elves' loads, for planning who carries what.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    /// Every elf's load, in input order.
+    pub loads: Vec<Load>,
+    pub items: usize,
+    pub calories: u64,
+    pub min: u64,
+    pub max: u64,
+    pub mean: f64,
+    pub median: f64,
+    /// Calories at each of [`PERCENTILES`].
+    pub percentiles: Vec<(u8, u64)>,
+    /// Number of elves carrying each number of items.
+    pub item_counts: BTreeMap<usize, usize>,
+    /// Elves per range of calories, from the lightest load to the heaviest.
+    pub histogram: Vec<Bucket>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Load {
+    /// Position of the elf's inventory in the input, from 0.
+    pub index: usize,
+    pub items: usize,
+    pub calories: u64,
+}
+
+/// Elves carrying from `from` to `to` calories, both included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bucket {
+    pub from: u64,
+    pub to: u64,
+    pub elves: usize,
+}
+
+impl Report {
+    /// Report over at least one elf.
+    pub fn new(elves: &[Elf]) -> Result<Report, NotEnoughElves> {
+        if elves.is_empty() {
+            return Err(NotEnoughElves { wanted: 1, found: 0 });
+        }
+
+        let loads: Vec<Load> = elves.iter()
+            .map(|elf| Load { index: elf.index, items: elf.items.len(), calories: elf.calories() })
+            .collect();
+        let mut sorted: Vec<u64> = loads.iter().map(|load| load.calories).collect();
+        sorted.sort_unstable();
+
+        let count = sorted.len();
+        let calories: u64 = sorted.iter().sum();
+        let median = match count % 2 {
+            1 => sorted[count / 2] as f64,
+            _ => (sorted[count / 2 - 1] as f64 + sorted[count / 2] as f64) / 2.0,
+        };
+        let percentiles = PERCENTILES.iter()
+            .map(|&p| (p, sorted[(p as usize * count).div_ceil(100).max(1) - 1]))
+            .collect();
+
+        let mut item_counts = BTreeMap::new();
+        for load in &loads {
+            *item_counts.entry(load.items).or_insert(0) += 1;
+        }
+
+        return Ok(Report {
+            items: loads.iter().map(|load| load.items).sum(),
+            calories,
+            min: sorted[0],
+            max: sorted[count - 1],
+            mean: calories as f64 / count as f64,
+            median,
+            percentiles,
+            item_counts,
+            histogram: histogram(&sorted),
+            loads,
+        });
+    }
+
+    /// One row per elf: `elf,items,calories`, elves numbered from 1.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("elf,items,calories\n");
+        for load in &self.loads {
+            csv += &format!("{},{},{}\n", load.index + 1, load.items, load.calories);
+        }
+
+        return csv;
+    }
+
+    /// The statistics, then every elf's load, elves numbered from 1.
+    pub fn to_json(&self) -> Json {
+        let percentiles = self.percentiles.iter()
+            .fold(Json::object(), |json, (p, calories)| json.field(&format!("p{}", p), *calories));
+        let item_counts: Vec<Json> = self.item_counts.iter()
+            .map(|(&items, &elves)| Json::object().field("items", items).field("elves", elves))
+            .collect();
+        let histogram: Vec<Json> = self.histogram.iter()
+            .map(|bucket| Json::object().field("from", bucket.from).field("to", bucket.to).field("elves", bucket.elves))
+            .collect();
+        let loads: Vec<Json> = self.loads.iter()
+            .map(|load| Json::object().field("elf", load.index + 1).field("items", load.items).field("calories", load.calories))
+            .collect();
+
+        return Json::object()
+            .field("elves", self.loads.len())
+            .field("items", self.items)
+            .field("calories", self.calories)
+            .field("min", self.min)
+            .field("max", self.max)
+            .field("mean", self.mean)
+            .field("median", self.median)
+            .field("percentiles", percentiles)
+            .field("item_counts", item_counts)
+            .field("histogram", histogram)
+            .field("loads", loads);
+    }
+}
+
+/// Splits the range of loads into at most [`HISTOGRAM_BUCKETS`] ranges of
+/// equal width and counts the elves in each.
+fn histogram(sorted: &[u64]) -> Vec<Bucket> {
+    let (min, max) = (sorted[0], sorted[sorted.len() - 1]);
+    let width = (max - min + 1).div_ceil(HISTOGRAM_BUCKETS);
+
+    let mut buckets = Vec::new();
+    let mut from = min;
+    while from <= max {
+        let to = (from + width - 1).min(max);
+        let elves = sorted.iter().filter(|&&calories| (from..=to).contains(&calories)).count();
+        buckets.push(Bucket { from, to, elves });
+        from = to + 1;
+    }
+
+    return buckets;
+}
+
+/// Summary, item-count distribution and histogram, for reading in a terminal.
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Elves: {}, items: {}, calories: {}", self.loads.len(), self.items, self.calories)?;
+        writeln!(f, "Mean: {:.1}, median: {:.1}, min: {}, max: {}", self.mean, self.median, self.min, self.max)?;
+        let percentiles: Vec<String> = self.percentiles.iter().map(|(p, calories)| format!("p{} {}", p, calories)).collect();
+        writeln!(f, "Percentiles: {}", percentiles.join(", "))?;
+
+        writeln!(f, "\nItems per elf:")?;
+        let most_elves = self.item_counts.values().copied().max().unwrap_or(0);
+        for (items, &elves) in &self.item_counts {
+            writeln!(f, "{:>6} | {} {}", items, bar(elves, most_elves), elves)?;
+        }
+
+        writeln!(f, "\nCalories per elf:")?;
+        let width = self.max.to_string().len();
+        let most_elves = self.histogram.iter().map(|bucket| bucket.elves).max().unwrap_or(0);
+        for bucket in &self.histogram {
+            writeln!(f, "{:>width$} - {:>width$} | {} {}", bucket.from, bucket.to, bar(bucket.elves, most_elves), bucket.elves, width = width)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Bar scaled against the longest one, at least one character unless empty.
+fn bar(count: usize, most: usize) -> String {
+    let length = match count {
+        0 => 0,
+        _ => (count * BAR_WIDTH / most).max(1),
+    };
+    return "#".repeat(length);
+}
+
+/// How a report or a plan is written to stdout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReportFormat {
+    #[default]
+    Text,
+    Csv,
+    Json,
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<ReportFormat, String> {
+        match s.trim() {
+            "text" => Ok(ReportFormat::Text),
+            "csv" => Ok(ReportFormat::Csv),
+            "json" => Ok(ReportFormat::Json),
+            other => Err(format!("Unknown report format '{}', expected text, csv or json", other)),
+        }
+    }
+}
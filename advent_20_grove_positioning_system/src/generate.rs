@@ -0,0 +1,28 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::GrovePositioningSystem;
+
+/// File of `size` numbers with exactly one 0, which the grove coordinates
+/// are counted from.
+impl Generate for GrovePositioningSystem {
+    const DEFAULT_SIZE: usize = 5000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(2);
+        let zero = rng.below(size);
+
+        let mut input = String::new();
+        for i in 0..size {
+            let number = match i {
+                _ if i == zero => 0,
+                _ => {
+                    let magnitude = rng.between(1..=10000);
+                    if rng.chance(0.5) { magnitude } else { -magnitude }
+                }
+            };
+            input.push_str(&format!("{}\n", number));
+        }
+
+        return input;
+    }
+}
@@ -0,0 +1,107 @@
+mod generate;
+
+use aoc_core::parse::{self, numbered_lines};
+use aoc_core::{DayConfig, ParseError, Puzzle, Result};
+
+const DECRYPTION_KEY: i64 = 811589153;
+
+pub struct GrovePositioningSystem;
+
+impl Puzzle for GrovePositioningSystem {
+    const DAY: u8 = 20;
+
+    type Parsed = EncryptedFile;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(file: &Self::Parsed) -> Result<Self::Answer1> {
+        part1(&file.numbers)
+    }
+
+    fn part2(file: &Self::Parsed) -> Result<Self::Answer2> {
+        part2(&file.numbers, file.decryption_key)
+    }
+
+    fn configure(file: &mut Self::Parsed, config: &DayConfig) -> Result<()> {
+        file.decryption_key = config.get("decryption_key", DECRYPTION_KEY)?;
+        Ok(())
+    }
+}
+
+pub struct EncryptedFile {
+    numbers: Vec<(i64, i64)>,
+    decryption_key: i64,
+}
+
+fn part1(numbers: &[(i64, i64)]) -> Result<i64> {
+    Ok(decrypt(numbers, 1, 1))
+}
+
+fn part2(numbers: &[(i64, i64)], decryption_key: i64) -> Result<i64> {
+    Ok(decrypt(numbers, decryption_key, 10))
+}
+
+fn decrypt(numbers_v: &[(i64, i64)], key: i64, rounds: u32) -> i64 {
+    let numbers_v: Vec<(i64, i64)> = numbers_v.iter().map(|x| (x.0, x.1 * key)).collect();
+    let mut numbers = numbers_v.clone();
+    // A lone number has nowhere to move; keep the modulus non-zero for it
+    let length = (numbers.len() as i64 - 1).max(1);
+
+    for _ in 0..rounds {
+        for d in &numbers_v {
+            let pos = numbers.iter().position(|n| n == d).unwrap() as i64;
+            let mut new_pos = (pos + d.1) % length;
+    
+            if new_pos < 0 {
+                new_pos += length;
+            }
+    
+            if new_pos >= length {
+                new_pos -= length;
+            }
+    
+            let n = numbers.remove(pos as usize);
+            numbers.insert(new_pos as usize, n);          
+        }   
+    }
+    let zero_idx = numbers.iter().position(|x| x.1 == 0).expect("Number should be present");
+
+    let idx_1000th = (zero_idx + 1000) % numbers.len();
+    let n1 = numbers[idx_1000th];
+    let idx_2000th = (zero_idx + 2000) % numbers.len();
+    let n2 = numbers[idx_2000th];
+    let idx_3000th = (zero_idx + 3000) % numbers.len();
+    let n3 = numbers[idx_3000th];
+
+    n1.1 + n2.1 + n3.1
+}
+
+/// Encrypted file numbers paired with their original position.
+fn parse(input: &str) -> Result<EncryptedFile> {
+    let numbers = numbered_lines(input)
+        .map(|(line_number, line)| Ok(((line_number - 1) as i64, parse::number(line_number, line, line)?)))
+        .collect::<std::result::Result<Vec<_>, ParseError>>()?;
+
+    if !numbers.iter().any(|n| n.1 == 0) {
+        return Err(Box::new(ParseError::end_of_input(input, "a 0 somewhere in the file")));
+    }
+
+    Ok(EncryptedFile { numbers, decryption_key: DECRYPTION_KEY })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_lone_zero_is_left_in_place() {
+        let file = parse("0\n").unwrap();
+
+        assert_eq!(part1(&file.numbers).unwrap(), 0);
+        assert_eq!(part2(&file.numbers, file.decryption_key).unwrap(), 0);
+    }
+}
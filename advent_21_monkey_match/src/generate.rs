@@ -0,0 +1,121 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::MonkeyMath;
+
+/// Letters of generated monkey names; "root" and "humn" are never made up
+/// from them by chance since 'u' and 'o' are left out.
+const NAME_LETTERS: &str = "abcdefghijklmnpqrstvwxyz";
+
+/// Largest value multiplied by another job, so the numbers stay far from
+/// overflowing.
+const MULTIPLY_LIMIT: u64 = 1_000_000_000_000;
+
+/// Largest factor the number you yell is multiplied by on the way to `root`.
+const SLOPE_LIMIT: u64 = 1_000_000_000;
+
+/// Jobs of about `size` monkeys. The solver works on unsigned numbers, so
+/// every job is built from the value it must produce: differences never go
+/// below zero and divisions are exact. `humn` sits a few dozen jobs below
+/// `root`, only behind `+`, `*` and `-` with it on the left, so the value
+/// that balances `root` can always be found.
+impl Generate for MonkeyMath {
+    const DEFAULT_SIZE: usize = 2000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut jobs = Jobs { rng, names: Vec::new(), lines: Vec::new() };
+        let size = size.max(3);
+        let depth = (size / 30).clamp(1, 70);
+        let subtree_size = (size / (depth + 1)).max(1);
+
+        let balanced_humn = jobs.rng.between(1..=1000) as u64;
+        let humn = jobs.rng.between(balanced_humn as i64..=balanced_humn as i64 + 1000) as u64;
+        jobs.lines.push(format!("humn: {}", humn));
+
+        let mut name = String::from("humn");
+        let mut value = balanced_humn;
+        let mut slope = 1;
+        for _ in 0..depth {
+            let parent = jobs.name();
+            let (operation, other_value) = match jobs.rng.below(3) {
+                0 if value < MULTIPLY_LIMIT && slope < SLOPE_LIMIT => ('*', jobs.rng.between(2..=5) as u64),
+                1 if value > 0 => ('-', jobs.rng.between(0..=value as i64) as u64),
+                _ => ('+', jobs.rng.between(0..=1000) as u64),
+            };
+            let other = jobs.subtree(other_value, subtree_size);
+
+            let human_left = operation == '-' || jobs.rng.chance(0.5);
+            let (left, right) = if human_left { (&name, &other) } else { (&other, &name) };
+            jobs.lines.push(format!("{}: {} {} {}", parent, left, operation, right));
+
+            if operation == '*' {
+                slope *= other_value;
+            }
+            value = match operation {
+                '*' => value * other_value,
+                '-' => value - other_value,
+                _ => value + other_value,
+            };
+            name = parent;
+        }
+
+        let other = jobs.subtree(value, subtree_size);
+        jobs.lines.push(format!("root: {} + {}", name, other));
+
+        let Jobs { rng, mut lines, .. } = jobs;
+        rng.shuffle(&mut lines);
+        return lines.join("\n") + "\n";
+    }
+}
+
+struct Jobs<'a> {
+    rng: &'a mut Rng,
+    names: Vec<String>,
+    lines: Vec<String>,
+}
+
+impl Jobs<'_> {
+    fn name(&mut self) -> String {
+        loop {
+            let name: String = (0..4).map(|_| self.rng.char_of(NAME_LETTERS)).collect();
+            if !self.names.contains(&name) {
+                self.names.push(name.clone());
+                return name;
+            }
+        }
+    }
+
+    /// Name of a monkey yelling `value` after about `size` jobs.
+    fn subtree(&mut self, value: u64, size: usize) -> String {
+        let name = self.name();
+        if size < 3 {
+            self.lines.push(format!("{}: {}", name, value));
+            return name;
+        }
+
+        let divisors: Vec<u64> = (2..=9).filter(|&divisor| value.is_multiple_of(divisor)).collect();
+        let (left, operation, right) = match self.rng.below(4) {
+            0 if !divisors.is_empty() => {
+                let divisor = *self.rng.pick(&divisors);
+                (value / divisor, '*', divisor)
+            }
+            1 if value < MULTIPLY_LIMIT => {
+                let divisor = self.rng.between(2..=9) as u64;
+                (value * divisor, '/', divisor)
+            }
+            2 => {
+                let right = self.rng.between(0..=100) as u64;
+                (value + right, '-', right)
+            }
+            _ => {
+                let left = self.rng.between(0..=value.min(i64::MAX as u64) as i64) as u64;
+                (left, '+', value - left)
+            }
+        };
+
+        let left_size = self.rng.below(size - 1);
+        let left = self.subtree(left, left_size);
+        let right = self.subtree(right, size - 1 - left_size);
+        self.lines.push(format!("{}: {} {} {}", name, left, operation, right));
+        return name;
+    }
+}
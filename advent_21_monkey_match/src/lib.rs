@@ -0,0 +1,208 @@
+mod generate;
+
+use std::collections::HashMap;
+
+use aoc_core::parse::{self, numbered_lines};
+use aoc_core::{ParseError, Puzzle, Result};
+
+#[derive(Debug, Clone)]
+pub enum Job {
+    Number(u64),
+    Arithmetic(String, Operation, String)
+}
+
+#[derive(Debug, Clone)]
+pub enum Operation {
+    Plus,
+    Minus,
+    Multiply,
+    Divide,
+}
+
+pub struct MonkeyMath;
+
+impl Puzzle for MonkeyMath {
+    const DAY: u8 = 21;
+
+    type Parsed = HashMap<String, Job>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(jobs: &Self::Parsed) -> Result<Self::Answer1> {
+        part1(jobs)
+    }
+
+    fn part2(jobs: &Self::Parsed) -> Result<Self::Answer2> {
+        part2(jobs)
+    }
+}
+
+fn part1(jobs: &HashMap<String, Job>) -> Result<u64> {
+    let answer = find_answers("root".to_string(), jobs);
+    Ok(answer)
+}
+
+fn part2(jobs: &HashMap<String, Job>) -> Result<u64> {
+    let root = "root".to_string();
+    let path = find_human("root".to_string(), jobs).unwrap();
+    let path = path.iter().rev().collect::<Vec<_>>();
+
+    let (left, right) = match jobs.get(&root).unwrap() {
+        Job::Number(_) => panic!("root monkey has no data"),
+        Job::Arithmetic(left, _, right) => (left, right),
+    };
+
+    let correct_val = if left == path[1] {
+        find_answers(right.to_string(), jobs)
+    } else {
+        find_answers(left.to_string(), jobs)
+    };
+    
+    Ok(find_adjustment(&path, 1, jobs, correct_val))
+}
+
+fn find_adjustment(
+    path: &Vec<&String>,
+    index: usize,
+    tree: &HashMap<String, Job>,
+    cv: u64,
+) -> u64 {
+    match tree.get(path[index]).unwrap() {
+        Job::Number(_) => cv,
+        Job::Arithmetic(l, op, r) => {
+            let left = find_answers(l.to_string(), tree);
+            let right = find_answers(r.to_string(), tree);
+            let new_cv = if l == path[index + 1] {
+                match op {
+                    Operation::Plus => cv - right,
+                    Operation::Minus => cv + right,
+                    Operation::Multiply => cv / right,
+                    Operation::Divide => cv * right,
+                }
+            } else {
+                match op {
+                    Operation::Plus => cv - left,
+                    Operation::Minus => left - cv,
+                    Operation::Multiply => cv / left,
+                    Operation::Divide => left / cv,
+                }
+            };
+            find_adjustment(path, index + 1, tree, new_cv)
+        }
+    }
+}
+
+fn find_answers(loc: String, jobs: &HashMap<String, Job>) -> u64 {
+    let mut answers = HashMap::new();
+
+    let number_jobs = jobs.iter().filter_map(|x| match x {
+        (_, Job::Arithmetic(_, _, _)) => None,
+        (name, Job::Number(x)) => Some((name, *x))
+    });
+
+    for (s, n) in number_jobs {
+        answers.insert(s.to_string(), n);
+    }
+
+    let arithmetic_jobs = jobs.iter().filter_map(|x| match x {
+        (_, Job::Number(_)) => None,
+        (name, job) => Some((name.to_string(), job.clone()))
+    }).collect::<Vec<(String, Job)>>();
+
+    loop {
+        for (name, job) in &arithmetic_jobs {
+            match job {
+                Job::Arithmetic(n1, op, n2) => {
+                    if answers.contains_key(n1) && answers.contains_key(n2) {
+                        let numb1 = answers[n1];
+                        let numb2 = answers[n2];
+                        match op {
+                            Operation::Plus => answers.insert(name.to_string(), numb1 + numb2).map(|_| ()),
+                            Operation::Minus => answers.insert(name.to_string(), numb1 - numb2).map(|_| ()),
+                            Operation::Multiply => answers.insert(name.to_string(), numb1 * numb2).map(|_| ()),
+                            Operation::Divide => answers.insert(name.to_string(), numb1 / numb2).map(|_| ()),
+                        };
+                    }
+
+                    continue;
+                },
+                _ => panic!("Expect filtered jobs")
+            }
+        }
+
+        if let Some(val) = answers.get(&loc) {
+            return *val;
+        }
+    }
+}
+
+fn find_human(loc: String, jobs: &HashMap<String, Job>) -> Option<Vec<String>> {
+    if loc == "humn" {
+        return Some(vec![loc])
+    }
+
+    if let Some(Job::Arithmetic(l, _, r)) = jobs.get(&loc) {
+        if let Some(mut vec) = find_human(l.to_string(), jobs) {
+            vec.push(loc);
+            return Some(vec)
+        }
+        if let Some(mut vec) = find_human(r.to_string(), jobs) {
+            vec.push(loc);
+            return Some(vec)
+        }
+    }
+
+    None
+}
+
+
+/// Job of every monkey, keyed by monkey name.
+fn parse(input: &str) -> Result<HashMap<String, Job>> {
+    let mut jobs = HashMap::new();
+    let mut references = Vec::new();
+
+    for (line_number, i) in numbered_lines(input) {
+        let (name, job_str) = i.split_once(": ")
+            .ok_or_else(|| ParseError::new(line_number, i, i, "a job like 'root: pppw + sjmn' or 'dbpl: 5'"))?;
+
+        if job_str.starts_with(|c: char| c.is_ascii_digit()) {
+            let job = Job::Number(parse::number(line_number, i, job_str)?);
+            jobs.insert(name.to_string(), job);
+        } else {
+            let job_split = job_str.split(" ").collect::<Vec<&str>>();
+            let &[name_1, op, name_2] = job_split.as_slice() else {
+                return Err(Box::new(ParseError::new(line_number, i, job_str, "a number or an operation like 'pppw + sjmn'")));
+            };
+            
+            let op = match op {
+                "+" => Operation::Plus,
+                "-" => Operation::Minus,
+                "*" => Operation::Multiply,
+                "/" => Operation::Divide,
+                _ => return Err(Box::new(ParseError::new(line_number, i, op, "an operator '+', '-', '*' or '/'"))),
+            };
+            references.push((line_number, i, name_1));
+            references.push((line_number, i, name_2));
+
+            let job = Job::Arithmetic(name_1.to_string(), op, name_2.to_string());
+            jobs.insert(name.to_string(), job);
+        }
+    }
+
+    for (line_number, line, name) in references {
+        if !jobs.contains_key(name) {
+            return Err(Box::new(ParseError::new(line_number, line, name, "the name of a monkey with a job")));
+        }
+    }
+    for name in ["root", "humn"] {
+        if !jobs.contains_key(name) {
+            return Err(Box::new(ParseError::end_of_input(input, format!("a job for '{}'", name))));
+        }
+    }
+
+    Ok(jobs)
+}
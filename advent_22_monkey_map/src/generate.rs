@@ -0,0 +1,46 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::MonkeyMap;
+
+/// Faces of the cube net the real input is folded from, as (row, column) in
+/// face units.
+const NET: [(usize, usize); 6] = [(0, 1), (0, 2), (1, 1), (2, 0), (2, 1), (3, 0)];
+
+/// Board of the real input's cube net with faces `size` tiles wide, about a
+/// tenth of the tiles walls, followed by a path of 1000 moves and turns. The
+/// starting tile is always open.
+impl Generate for MonkeyMap {
+    const DEFAULT_SIZE: usize = 50;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let side = size.max(1);
+        let mut input = String::new();
+
+        for row in 0..side * 4 {
+            let columns: Vec<usize> = NET.iter().filter(|face| face.0 == row / side).map(|face| face.1).collect();
+            let last_column = *columns.iter().max().unwrap();
+
+            for column in 0..(last_column + 1) * side {
+                let tile = match column {
+                    _ if !columns.contains(&(column / side)) => ' ',
+                    _ if row == 0 && column == side => '.',
+                    _ if rng.chance(0.1) => '#',
+                    _ => '.',
+                };
+                input.push(tile);
+            }
+            input.push('\n');
+        }
+
+        input.push('\n');
+        for step in 0..1000 {
+            if step > 0 {
+                input.push(rng.char_of("LR"));
+            }
+            input.push_str(&rng.between(1..=50).to_string());
+        }
+        input.push('\n');
+
+        return input;
+    }
+}
@@ -0,0 +1,200 @@
+mod generate;
+
+use aoc_core::geometry::{Direction, Turn};
+use aoc_core::grid::{Grid, Position};
+use aoc_core::parse;
+use aoc_core::{verbose, ParseError, Part, Puzzle, Result, Unsolved};
+use regex::Regex;
+use Command::*;
+use Direction::*;
+use Block::*;
+
+/// Board tiles keyed by zero-based (column, row).
+pub type Board = Grid<Block>;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Block {
+    Empty,
+    Wall
+}
+
+#[derive(Debug)]
+pub enum Command {
+    Move(u32),
+    Turn(Turn)
+}
+
+struct State {
+    direction: Direction,
+    pos: Position,
+    map: Board
+}
+
+impl State {
+    fn init(map: Board) -> State {
+        let start_pos = State::get_start_pos(&map);
+
+        State {
+            direction: East,
+            pos: start_pos,
+            map
+        }
+    }
+
+    fn get_start_pos(map: &Board) -> Position {
+        let top_row = map.bounds().expect("Board has tiles").min.y;
+        map.row(top_row).next().unwrap().0
+    }
+
+    fn get_next_pos(&self) -> Option<Position> {
+        let new_pos = self.lookup_next_pos();
+
+        if let Some(b) = self.map.get(new_pos) {
+            if *b != Wall {
+                return Some(new_pos);
+            } else {
+                return None;
+            }
+        }
+
+        // Off the board, wrap around to the other end of the row or column
+        let Position { x, y } = self.pos;
+        let wrapped = match self.direction {
+            East => self.map.row(y).next(),
+            West => self.map.row(y).next_back(),
+            South => self.map.column(x).next(),
+            North => self.map.column(x).next_back(),
+        };
+
+        return match wrapped.unwrap() {
+            (_, Wall) => None,
+            (pos, _) => Some(pos)
+        }
+    }
+
+    fn move_to(&mut self, new_pos: Position) {
+        self.pos = new_pos;
+    }
+
+    fn lookup_next_pos(&self) -> Position {
+        self.pos + self.direction.offset()
+    }
+
+    fn turn(&mut self, turn: Turn) {
+        self.direction = self.direction.turn(turn);
+    }
+
+    /// Facing value of the password: 0 for right, 1 for down, 2 for left
+    /// and 3 for up.
+    fn facing(&self) -> i32 {
+        match self.direction {
+            East => 0,
+            South => 1,
+            West => 2,
+            North => 3,
+        }
+    }
+}
+
+pub struct MonkeyMap;
+
+impl Puzzle for MonkeyMap {
+    const DAY: u8 = 22;
+
+    type Parsed = (Board, Vec<Command>);
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(notes: &Self::Parsed) -> Result<Self::Answer1> {
+        part1(notes)
+    }
+
+    fn part2(_notes: &Self::Parsed) -> Result<Self::Answer2> {
+        part2(_notes)
+    }
+}
+
+fn part1((coordinates, directions): &(Board, Vec<Command>)) -> Result<i32> {
+    let mut state = State::init(coordinates.clone());
+    for d in directions {
+        match d {
+            Move(steps) => {
+                for _ in 0..*steps {
+                    if let Some(coord) = state.get_next_pos() {
+                        state.move_to(coord);
+
+                    } else { break; }
+                }
+            },
+            Turn(turn) => state.turn(*turn)
+        }
+    }
+
+    let (column, row) = (state.pos.x + 1, state.pos.y + 1);
+    verbose!("Final row {} column {} facing {}", row, column, state.facing());
+    Ok(1000 * row + 4 * column + state.facing())
+}
+
+fn part2(_notes: &(Board, Vec<Command>)) -> Result<i32> {
+    Err(Box::new(Unsolved(Part::Two)))
+}
+
+/// Board tiles and the path to follow.
+fn parse(input: &str) -> Result<(Board, Vec<Command>)> {
+    let mut directions = Vec::new();
+    let regex = Regex::new(r"(\d+|R|L)")?;
+
+    // The board runs up to the first blank line after it
+    let lines = input.lines().collect::<Vec<&str>>();
+    let board_start = lines.iter().position(|line| !line.is_empty()).unwrap_or(lines.len());
+    let board_end = lines[board_start..].iter().position(|line| line.is_empty()).map_or(lines.len(), |i| board_start + i);
+
+    let coordinates = Grid::parse(&lines[..board_end].join("\n"), &[' '], "a tile ' ', '.' or '#'", |c| match c {
+        '.' => Some(Block::Empty),
+        '#' => Some(Block::Wall),
+        _ => None
+    })?;
+
+    for (y, line) in lines.iter().enumerate().skip(board_end) {
+        let line_number = y + 1;
+
+        if !line.is_empty() {
+            if !directions.is_empty() {
+                return Err(Box::new(ParseError::new(line_number, line, line, "a single line of path instructions")));
+            }
+
+            let mut end = 0;
+            for i in regex.find_iter(line) {
+                if i.start() != end {
+                    return Err(Box::new(ParseError::at_char(line_number, line, end, "a step count or a turn 'R' or 'L'")));
+                }
+                end = i.end();
+
+                let direction = i.as_str();
+                if direction == "L" {
+                    directions.push(Command::Turn(Turn::Left));
+                } else if direction == "R" {
+                    directions.push(Command::Turn(Turn::Right));
+                } else {
+                    directions.push(Command::Move(parse::number(line_number, line, direction)?));
+                }
+            }
+            if end != line.len() {
+                return Err(Box::new(ParseError::at_char(line_number, line, end, "a step count or a turn 'R' or 'L'")));
+            }
+        }
+    }
+
+    if coordinates.is_empty() {
+        return Err(Box::new(ParseError::end_of_input(input, "a board of '.' and '#' tiles")));
+    }
+    if directions.is_empty() {
+        return Err(Box::new(ParseError::end_of_input(input, "a line of path instructions after a blank line")));
+    }
+
+    Ok((coordinates, directions))
+}
\ No newline at end of file
@@ -0,0 +1,19 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::UnstableDiffusion;
+
+/// Square scan of `size` by `size` positions, about half of them elves and
+/// always at least one.
+impl Generate for UnstableDiffusion {
+    const DEFAULT_SIZE: usize = 70;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let mut tiles: Vec<char> = (0..size * size).map(|_| if rng.chance(0.5) { '#' } else { '.' }).collect();
+        let elf = rng.below(tiles.len());
+        tiles[elf] = '#';
+
+        let rows: Vec<String> = tiles.chunks(size).map(|row| row.iter().collect()).collect();
+        return rows.join("\n") + "\n";
+    }
+}
@@ -0,0 +1,140 @@
+mod generate;
+mod visualize;
+
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+
+use aoc_core::geometry::Direction;
+use aoc_core::grid::{Grid, Position};
+use aoc_core::{verbose, DayConfig, Part, Puzzle, Result, Unsolved};
+
+#[derive(Debug, Clone)]
+pub struct State {
+    /// Elves within the scanned rectangle, which they never leave.
+    elfs: Grid<()>,
+    directions: Vec<Direction>,
+    rounds: u32,
+}
+
+impl State {
+    fn init(elfs: Grid<()>) -> State {
+        use Direction::*;
+
+        State {
+            elfs,
+            directions: vec![North, South, West, East],
+            rounds: 10
+        }
+    }
+
+    fn is_alone(&self, elf_coords: &Position) -> bool {
+        return elf_coords.neighbours8().iter().all(|&neighbour| !self.elfs.contains(neighbour));
+    }
+
+    fn get_proposed_coordinates(&self, elf_coords: &Position) -> Option<Position> {
+        for &d in &self.directions {
+           let adjecent = get_adjacent_coords_by_direction(d, elf_coords);
+
+           if self.elfs.is_inside(adjecent[1]) && adjecent.iter().all(|&coords| !self.elfs.contains(coords)) {
+                return Some(adjecent[1]);
+           }
+        }
+
+        None
+    }
+
+    /// Every elf with a neighbour proposes a move, those alone in proposing
+    /// their position make it, and the first direction considered rotates
+    /// to the back.
+    fn round(&mut self) {
+        let mut proposals = HashMap::new();
+
+        for (coords, _) in self.elfs.iter() {
+            if self.is_alone(&coords) {
+                continue;
+            }
+
+            if let Some(proposed) = self.get_proposed_coordinates(&coords) {
+                if let Entry::Vacant(e) = proposals.entry(proposed) {
+                    e.insert(coords);
+                } else {
+                    _ = proposals.remove(&proposed);
+                }
+            }
+        }
+
+        for (prop, original_coords) in proposals {
+            _ = self.elfs.remove(original_coords);
+            self.elfs.insert(prop, ());
+        }
+
+        let direction = self.directions.remove(0);
+        self.directions.push(direction);
+    }
+}
+
+/// The three positions an elf looks at before moving in `direction`: ahead
+/// on its left, straight ahead and ahead on its right.
+fn get_adjacent_coords_by_direction(direction: Direction, coords: &Position) -> [Position; 3] {
+    let ahead = *coords + direction.offset();
+
+    [ahead + direction.turn_left().offset(), ahead, ahead + direction.turn_right().offset()]
+}
+
+pub struct UnstableDiffusion;
+
+impl Puzzle for UnstableDiffusion {
+    const DAY: u8 = 23;
+
+    type Parsed = State;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(state: &Self::Parsed) -> Result<Self::Answer1> {
+        part1(state)
+    }
+
+    fn part2(_state: &Self::Parsed) -> Result<Self::Answer2> {
+        part2(_state)
+    }
+
+    fn configure(state: &mut Self::Parsed, config: &DayConfig) -> Result<()> {
+        state.rounds = config.get("rounds", state.rounds)?;
+        Ok(())
+    }
+}
+
+fn part1(state: &State) -> Result<u32> {
+    let mut state = state.clone();
+    for _ in 0..state.rounds {
+        state.round();
+    }
+
+    verbose!("{}", render_elfs(&state));
+    Ok(calculate_empty_spaces(&state))
+}
+
+fn part2(_state: &State) -> Result<u32> {
+    Err(Box::new(Unsolved(Part::Two)))
+}
+
+fn render_elfs(state: &State) -> String {
+    return state.elfs.render(|_, elf| if elf.is_some() { '#' } else { '.' });
+}
+
+/// Empty ground tiles in the smallest rectangle holding every elf.
+fn calculate_empty_spaces(state: &State) -> u32 {
+    let area = state.elfs.bounds().map_or(0, |bounds| bounds.area());
+
+    (area - state.elfs.len()) as u32
+}
+
+fn parse(input: &str) -> Result<State> {
+    let elfs = Grid::parse(input, &[' ', '.'], "an elf '#' or empty ground '.'", |c| (c == '#').then_some(()))?;
+
+    Ok(State::init(elfs))
+}
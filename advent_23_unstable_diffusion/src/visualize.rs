@@ -0,0 +1,26 @@
+use aoc_core::visualize::{Frames, Visualize};
+use aoc_core::Result;
+
+use crate::{calculate_empty_spaces, render_elfs, State, UnstableDiffusion};
+
+/// The elves one round per frame for the configured number of rounds, with
+/// the empty ground around them counted in the caption.
+impl Visualize for UnstableDiffusion {
+    fn visualize(state: &State, frames: &mut Frames) -> Result<()> {
+        let mut state = state.clone();
+        if !frames.push("== Initial State ==", render_elfs(&state)) {
+            return Ok(());
+        }
+
+        for round in 1..=state.rounds {
+            state.round();
+
+            let caption = format!("== End of Round {} == {} empty ground tiles", round, calculate_empty_spaces(&state));
+            if !frames.push(caption, render_elfs(&state)) {
+                break;
+            }
+        }
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,36 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::BlizzardBasin;
+
+/// Valley `size` positions high and four times as wide inside its walls.
+/// Columns of the entry and the exit have no vertical blizzards, so none
+/// ever blow out of the valley.
+impl Generate for BlizzardBasin {
+    const DEFAULT_SIZE: usize = 25;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let height = size.max(2);
+        let width = height * 4;
+        let (entry, exit) = (1, width);
+
+        let mut input = String::new();
+        input.push_str(&wall(width, entry));
+        for _ in 0..height {
+            input.push('#');
+            for x in 1..=width {
+                let tiles = if x == entry || x == exit { "<>" } else { "<>^v" };
+                input.push(if rng.chance(0.4) { rng.char_of(tiles) } else { '.' });
+            }
+            input.push_str("#\n");
+        }
+        input.push_str(&wall(width, exit));
+
+        return input;
+    }
+}
+
+/// Top or bottom wall with one opening at `gap`.
+fn wall(width: usize, gap: usize) -> String {
+    let tiles: String = (0..width + 2).map(|x| if x == gap { '.' } else { '#' }).collect();
+    return tiles + "\n";
+}
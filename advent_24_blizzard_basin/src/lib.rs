@@ -0,0 +1,170 @@
+mod generate;
+mod visualize;
+
+use std::iter;
+
+use aoc_core::geometry::Direction;
+use aoc_core::grid::{Grid, Position};
+use aoc_core::search;
+use aoc_core::{verbose, ParseError, Puzzle, Result};
+
+pub struct State {
+    user: Position,
+    exit: Position,
+    map: Grid<Type>
+}
+
+impl State {
+    fn new(user: Position, exit: Position, map: Grid<Type>) -> State {
+        State {user, exit, map}
+    }
+
+    /// Width and height of the valley inside the walls, the span every
+    /// blizzard wraps around.
+    fn valley(&self) -> (i32, i32) {
+        let extent = self.map.extent().expect("Map has an entry and an exit");
+        (extent.max.x - 1, extent.max.y - 1)
+    }
+
+    /// Whether `position` is free of walls and blizzards after `minute`
+    /// minutes.
+    fn is_clear(&self, position: Position, minute: i32) -> bool {
+        match self.map.get(position) {
+            None | Some(Type::Wall) => false,
+            _ if position == self.user || position == self.exit => true,
+            _ => self.blizzards(position, minute).next().is_none(),
+        }
+    }
+
+    /// Directions of the blizzards on a valley `position` after `minute`
+    /// minutes.
+    fn blizzards(&self, position: Position, minute: i32) -> impl Iterator<Item = Direction> + '_ {
+        let (width, height) = self.valley();
+
+        Direction::ALL.into_iter().filter(move |&direction| {
+            // A blizzard blowing this way is here if it started `minute` steps back
+            let origin = position - direction.offset() * minute;
+            let origin = Position::new((origin.x - 1).rem_euclid(width) + 1, (origin.y - 1).rem_euclid(height) + 1);
+            self.map.get(origin) == Some(&Type::Blizzard(direction))
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Type {
+    Wall,
+    Ground,
+    Blizzard(Direction),
+}
+
+pub struct BlizzardBasin;
+
+impl Puzzle for BlizzardBasin {
+    const DAY: u8 = 24;
+
+    type Parsed = State;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(state: &Self::Parsed) -> Result<Self::Answer1> {
+        part1(state)
+    }
+
+    fn part2(state: &Self::Parsed) -> Result<Self::Answer2> {
+        part2(state)
+    }
+}
+
+fn part1(state: &State) -> Result<u32> {
+    verbose!("{}", render_map(state));
+
+    return crossing(state, state.user, state.exit, 0);
+}
+
+fn part2(state: &State) -> Result<u32> {
+    let there = crossing(state, state.user, state.exit, 0)?;
+    let back = crossing(state, state.exit, state.user, there)?;
+    let there_again = crossing(state, state.user, state.exit, there + back)?;
+
+    verbose!("Trips took {}, {} and {} minutes", there, back, there_again);
+    return Ok(there + back + there_again);
+}
+
+/// Fewest minutes to get from `from` to `to` setting off after `start`
+/// minutes.
+fn crossing(state: &State, from: Position, to: Position, start: u32) -> Result<u32> {
+    let route = route(state, from, to, start)?;
+    return Ok(route.len() as u32 - 1);
+}
+
+/// Quickest route from `from` to `to` setting off after `start` minutes, the
+/// position at every minute from the start, moving or waiting to stay out of
+/// the blizzards. The blizzards come back to where they started every
+/// `period` minutes, so the search runs over positions at a minute of that
+/// cycle.
+fn route(state: &State, from: Position, to: Position, start: u32) -> Result<Vec<Position>> {
+    let (width, height) = state.valley();
+    let period = width / gcd(width, height) * height;
+
+    let paths = search::bfs_until(
+        [(from, start as i32 % period)],
+        |&(position, minute)| {
+            iter::once(position).chain(position.neighbours4())
+                .filter(|&next| state.is_clear(next, minute + 1))
+                .map(|next| (next, (minute + 1) % period))
+                .collect::<Vec<(Position, i32)>>()
+        },
+        |&(position, _)| position == to,
+    );
+
+    let goal = paths.goal().ok_or(NoWayThrough)?;
+    let route = paths.path(goal).expect("The goal was reached");
+    return Ok(route.into_iter().map(|(position, _)| position).collect());
+}
+
+fn gcd(a: i32, b: i32) -> i32 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+#[derive(Debug)]
+pub struct NoWayThrough;
+
+impl std::error::Error for NoWayThrough {}
+
+impl std::fmt::Display for NoWayThrough {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The blizzards never leave a way through the valley")
+    }
+}
+
+fn render_map(state: &State) -> String {
+    return state.map.render(|position, tile| match tile {
+        Some(Type::Ground) if position == state.user => 'E',
+        Some(Type::Ground) if position == state.exit => 'X',
+        Some(Type::Ground) => '.',
+        Some(Type::Wall) => '#',
+        Some(Type::Blizzard(direction)) => direction.arrow(),
+        None => ' ',
+    });
+}
+
+fn parse(input: &str) -> Result<State> {
+    let map = Grid::parse(input, &[], "a tile '.', '#', '^', '>', 'v' or '<'", |c| {
+        match c {
+            '.' => Some(Type::Ground),
+            '#' => Some(Type::Wall),
+            _ => Direction::from_arrow(c).map(Type::Blizzard),
+        }})?;
+
+    let user_entry = map.row(0).find(|&(_, t)| t == &Type::Ground)
+        .ok_or_else(|| ParseError::end_of_line(1, input.lines().next().unwrap_or(""), "an entry '.' in the top wall"))?;
+    let max_height = map.extent().map_or(0, |extent| extent.max.y);
+    let exit_entry = map.row(max_height).find(|&(_, t)| t == &Type::Ground)
+        .ok_or_else(|| ParseError::end_of_input(input, "an exit '.' in the bottom wall"))?;
+
+    Ok(State::new(user_entry.0, exit_entry.0, map))
+}
\ No newline at end of file
@@ -0,0 +1,49 @@
+use aoc_core::grid::Position;
+use aoc_core::visualize::{Frames, Visualize};
+use aoc_core::Result;
+
+use crate::{route, BlizzardBasin, State, Type};
+
+/// Part 2's whole journey, there, back for the snacks and there again, one
+/// minute per frame: the expedition `E` among the blizzards, drawn as their
+/// arrow or as their count where several share a position.
+impl Visualize for BlizzardBasin {
+    fn visualize(state: &State, frames: &mut Frames) -> Result<()> {
+        let trips = [(state.user, state.exit), (state.exit, state.user), (state.user, state.exit)];
+        let mut minute = 0;
+
+        if !frames.push("Minute 0, trip 1 of 3", draw(state, state.user, 0)) {
+            return Ok(());
+        }
+
+        for (trip, (from, to)) in trips.into_iter().enumerate() {
+            for position in route(state, from, to, minute)?.into_iter().skip(1) {
+                minute += 1;
+
+                let caption = format!("Minute {}, trip {} of 3", minute, trip + 1);
+                if !frames.push(caption, draw(state, position, minute)) {
+                    return Ok(());
+                }
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+fn draw(state: &State, expedition: Position, minute: u32) -> String {
+    return state.map.render(|position, tile| match tile {
+        _ if position == expedition => 'E',
+        Some(Type::Wall) => '#',
+        None => ' ',
+        Some(_) if position == state.user || position == state.exit => '.',
+        Some(_) => {
+            let blizzards: Vec<_> = state.blizzards(position, minute as i32).collect();
+            match blizzards.as_slice() {
+                [] => '.',
+                [direction] => direction.arrow(),
+                several => char::from_digit(several.len() as u32, 10).unwrap_or('*'),
+            }
+        }
+    });
+}
@@ -0,0 +1,37 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::FullOfHotAir;
+
+/// `size` fuel requirements below a trillion, written in SNAFU.
+impl Generate for FullOfHotAir {
+    const DEFAULT_SIZE: usize = 111;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size.max(1) {
+            let digits = rng.between(1..=12) as u32;
+            let value = rng.between(1..=10i64.pow(digits) - 1);
+            input.push_str(&snafu(value));
+            input.push('\n');
+        }
+
+        return input;
+    }
+}
+
+fn snafu(mut value: i64) -> String {
+    let mut digits = Vec::new();
+    while value != 0 {
+        let (digit, carry) = match value % 5 {
+            0 => ('0', 0),
+            1 => ('1', 0),
+            2 => ('2', 0),
+            3 => ('=', 1),
+            _ => ('-', 1),
+        };
+        digits.push(digit);
+        value = value / 5 + carry;
+    }
+
+    return digits.into_iter().rev().collect();
+}
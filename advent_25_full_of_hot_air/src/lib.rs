@@ -0,0 +1,117 @@
+mod generate;
+
+use aoc_core::parse::numbered_lines;
+use aoc_core::{ParseError, Part, Puzzle, Result, Unsolved};
+
+pub struct State {
+    nums: Vec<String>
+}
+
+pub struct FullOfHotAir;
+
+impl Puzzle for FullOfHotAir {
+    const DAY: u8 = 25;
+
+    type Parsed = State;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(state: &Self::Parsed) -> Result<Self::Answer1> {
+        part1(state)
+    }
+
+    fn part2(_state: &Self::Parsed) -> Result<Self::Answer2> {
+        part2(_state)
+    }
+}
+
+/// Fuel requirements written as SNAFU numbers.
+fn parse(input: &str) -> Result<State> {
+    for (line_number, line) in numbered_lines(input) {
+        if let Some(i) = line.find(|c: char| !matches!(c, '0'..='2' | '-' | '=')) {
+            return Err(Box::new(ParseError::at_char(line_number, line, i, "a SNAFU digit '0', '1', '2', '-' or '='")));
+        }
+        if line.is_empty() {
+            return Err(Box::new(ParseError::end_of_line(line_number, line, "a SNAFU number")));
+        }
+    }
+
+    Ok(State { nums: input.lines().map(|x| x.to_string()).collect::<Vec<String>>() })
+}
+
+fn part1(state: &State) -> Result<String> {
+    let sum: i64 = state.nums.iter().map(|x| to_i64(x)).sum::<Result<i64>>()?;
+
+    Ok(to_snafu(sum))
+}
+
+fn part2(_state: &State) -> Result<String> {
+    Err(Box::new(Unsolved(Part::Two)))
+}
+
+fn to_i64(s: &str) -> Result<i64> {
+    let mut result = 0;
+    for c in s.chars() {
+        match c {
+            '0'..='2' => result = (result * 5) + (c as u8 - b'0') as i64,
+            '-' => result = result * 5 - 1,
+            '=' => result = result * 5 - 2,
+            other => return Err(format!("Bad SNAFU digit '{}' in {}", other, s).into())
+        }
+    }
+
+    Ok(result)
+}
+
+/// Balanced base 5 needs no sign: a negative number simply starts with '-'
+/// or '='. Euclidean division keeps every remainder in 0..5 for it.
+fn to_snafu(mut num: i64) -> String {
+    let mut result = String::new();
+
+    loop {
+        let n = num.rem_euclid(5);
+        match n {
+            0..=2 => {
+                result.push((n as u8 + b'0') as char);
+                num = num.div_euclid(5);
+            }
+            3 => {
+                result.push('=');
+                num = (num + 2).div_euclid(5);
+            },
+            4 => {
+                result.push('-');
+                num = (num + 1).div_euclid(5);
+            },
+            _ => unreachable!("rem_euclid(5) is in 0..5")
+        }
+
+        if num == 0 {
+            break;
+        }
+    }
+
+    result.chars().rev().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negative_numbers_round_trip() {
+        assert_eq!(to_snafu(-1), "-");
+        assert_eq!(to_snafu(-3), "-2");
+        assert_eq!(to_snafu(-4890), "=21-20");
+        assert_eq!(to_i64("=21-20").unwrap(), -4890);
+    }
+
+    #[test]
+    fn bad_digits_are_an_error() {
+        assert!(to_i64("12x").is_err());
+    }
+}
@@ -0,0 +1,20 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::RockPaperScissors;
+
+/// `size` rounds of the strategy guide.
+impl Generate for RockPaperScissors {
+    const DEFAULT_SIZE: usize = 2500;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size.max(1) {
+            input.push(rng.char_of("ABC"));
+            input.push(' ');
+            input.push(rng.char_of("XYZ"));
+            input.push('\n');
+        }
+
+        return input;
+    }
+}
@@ -0,0 +1,201 @@
+mod generate;
+
+use aoc_core::parse::numbered_lines;
+use aoc_core::{verbose, ParseError, Puzzle, Result};
+
+/// Strategy guide file read by this day instead of the usual `input.txt`.
+pub const INPUT: &str = "input_rpc.txt";
+
+/// Points both players score in one round: their shape plus the outcome.
+struct Round {
+    opponent_score: u8,
+    player_score: u8
+}
+
+impl Round {
+    fn new(opponent: Hand, player: Hand) -> Round {
+        return Round {
+            opponent_score: opponent as u8 + Outcome::of(opponent, player) as u8,
+            player_score: player as u8 + Outcome::of(player, opponent) as u8,
+        };
+    }
+}
+
+/// The strategy guide read both ways, as the opponent's hand and the
+/// player's hand of every round.
+pub struct StrategyGuide {
+    as_hands: Vec<(Hand, Hand)>,
+    as_outcomes: Vec<(Hand, Hand)>,
+}
+
+impl StrategyGuide {
+    pub fn rounds(&self, interpretation: Interpretation) -> &[(Hand, Hand)] {
+        match interpretation {
+            Interpretation::Hand => &self.as_hands,
+            Interpretation::Outcome => &self.as_outcomes,
+        }
+    }
+}
+
+pub struct RockPaperScissors;
+
+impl Puzzle for RockPaperScissors {
+    const DAY: u8 = 2;
+
+    type Parsed = StrategyGuide;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(guide: &StrategyGuide) -> Result<u32> {
+        Ok(total_score(guide.rounds(Interpretation::Hand)))
+    }
+
+    fn part2(guide: &StrategyGuide) -> Result<u32> {
+        Ok(total_score(guide.rounds(Interpretation::Outcome)))
+    }
+}
+
+/// Strategy guide rounds, with the second column read both ways.
+fn parse(input: &str) -> Result<StrategyGuide> {
+    let mut guide = StrategyGuide { as_hands: Vec::new(), as_outcomes: Vec::new() };
+    for (line_number, line) in numbered_lines(input) {
+        guide.as_hands.push(get_players_hands(line_number, line, Interpretation::Hand)?);
+        guide.as_outcomes.push(get_players_hands(line_number, line, Interpretation::Outcome)?);
+    }
+
+    return Ok(guide);
+}
+
+/// Player's score over all rounds, given as (opponent's hand, player's hand).
+fn total_score(rounds: &[(Hand, Hand)]) -> u32 {
+    let mut opponent_score: u32 = 0;
+    let mut player_score: u32 = 0;
+
+    for &(opponent, player) in rounds {
+        let round = Round::new(opponent, player);
+        opponent_score += round.opponent_score as u32;
+        player_score += round.player_score as u32;
+    }
+
+    verbose!("Opponent score: {}", opponent_score);
+    return player_score;
+}
+
+/// What the second column of the strategy guide stands for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Interpretation {
+    /// X, Y and Z are the hand to play: Rock, Paper and Scissors (part 1).
+    Hand,
+    /// X, Y and Z are how the round must end: Lose, Draw and Win (part 2).
+    Outcome,
+}
+
+/// Opponent's hand and the player's hand of one line of the strategy guide,
+/// reading the second column as `interpretation` says.
+pub fn get_players_hands(line_number: usize, turns_line: &str, interpretation: Interpretation) -> std::result::Result<(Hand, Hand), ParseError> {
+    if !turns_line.is_ascii() || turns_line.len() != 3 || &turns_line[1..2] != " " {
+        return Err(ParseError::new(line_number, turns_line, turns_line, "a round like 'A Y'"));
+    }
+
+    let opponent_turn = match &turns_line[0..1] {
+        "A" => Hand::Rock,
+        "B" => Hand::Paper,
+        "C" => Hand::Scissors,
+        x => return Err(ParseError::new(line_number, turns_line, x, "opponent hand 'A', 'B' or 'C'")),
+    };
+
+    let player_turn = match (&turns_line[2..3], interpretation) {
+        ("X", Interpretation::Hand) => Hand::Rock,
+        ("Y", Interpretation::Hand) => Hand::Paper,
+        ("Z", Interpretation::Hand) => Hand::Scissors,
+        ("X", Interpretation::Outcome) => Hand::for_outcome(opponent_turn, Outcome::Lose),
+        ("Y", Interpretation::Outcome) => Hand::for_outcome(opponent_turn, Outcome::Draw),
+        ("Z", Interpretation::Outcome) => Hand::for_outcome(opponent_turn, Outcome::Win),
+        (x, _) => return Err(ParseError::new(line_number, turns_line, x, "second column 'X', 'Y' or 'Z'")),
+    };
+
+    return Ok((opponent_turn, player_turn));
+}
+
+/// A shape, worth its discriminant in points.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Hand {
+    Rock = 1,
+    Paper = 2,
+    Scissors = 3
+}
+
+impl Hand {
+    /// The hand this one defeats.
+    pub fn beats(self) -> Hand {
+        match self {
+            Hand::Rock => Hand::Scissors,
+            Hand::Paper => Hand::Rock,
+            Hand::Scissors => Hand::Paper,
+        }
+    }
+
+    /// The hand to play against `opponent` for the round to end in `outcome`.
+    pub fn for_outcome(opponent: Hand, outcome: Outcome) -> Hand {
+        match outcome {
+            Outcome::Lose => opponent.beats(),
+            Outcome::Draw => opponent,
+            Outcome::Win => opponent.beats().beats(),
+        }
+    }
+}
+
+/// How a round ends for one player, worth its discriminant in points.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Lose = 0,
+    Draw = 3,
+    Win = 6
+}
+
+impl Outcome {
+    /// How the round ends for the player showing `hand` against `other`.
+    pub fn of(hand: Hand, other: Hand) -> Outcome {
+        if hand == other {
+            return Outcome::Draw;
+        }
+        if hand.beats() == other {
+            return Outcome::Win;
+        }
+        return Outcome::Lose;
+    }
+}
+
+/*
+Exercise:
+
+The Elves begin to set up camp on the beach. To decide whose tent gets to be closest to the snack storage, a giant Rock Paper Scissors tournament is already in progress.
+
+Rock Paper Scissors is a game between two players. Each game contains many rounds; in each round, the players each simultaneously choose one of Rock, Paper, or Scissors using a hand shape. Then, a winner for that round is selected: Rock defeats Scissors, Scissors defeats Paper, and Paper defeats Rock. If both players choose the same shape, the round instead ends in a draw.
+
+Appreciative of your help yesterday, one Elf gives you an encrypted strategy guide (your puzzle input) that they say will be sure to help you win. "The first column is what your opponent is going to play: A for Rock, B for Paper, and C for Scissors. The second column--" Suddenly, the Elf is called away to help with someone's tent.
+
+The second column, you reason, must be what you should play in response: X for Rock, Y for Paper, and Z for Scissors. Winning every time would be suspicious, so the responses must have been carefully chosen.
+
+The winner of the whole tournament is the player with the highest score. Your total score is the sum of your scores for each round. The score for a single round is the score for the shape you selected (1 for Rock, 2 for Paper, and 3 for Scissors) plus the score for the outcome of the round (0 if you lost, 3 if the round was a draw, and 6 if you won).
+
+Since you can't be sure if the Elf is trying to help you or trick you, you should calculate the score you would get if you were to follow the strategy guide.
+
+For example, suppose you were given the following strategy guide:
+
+A Y
+B X
+C Z
+This strategy guide predicts and recommends the following:
+
+In the first round, your opponent will choose Rock (A), and you should choose Paper (Y). This ends in a win for you with a score of 8 (2 because you chose Paper + 6 because you won).
+In the second round, your opponent will choose Paper (B), and you should choose Rock (X). This ends in a loss for you with a score of 1 (1 + 0).
+The third round is a draw with both players choosing Scissors, giving you a score of 3 + 3 = 6.
+In this example, if you were to follow the strategy guide, you would get a total score of 15 (8 + 1 + 6).
+
+What would your total score be if everything goes exactly according to your strategy guide?
+*/
\ No newline at end of file
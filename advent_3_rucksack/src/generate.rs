@@ -0,0 +1,54 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::RucksackReorganization;
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// `size` groups of three rucksacks. Each group shares exactly one badge and
+/// each rucksack exactly one item between its compartments.
+impl Generate for RucksackReorganization {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+
+        for _ in 0..size.max(1) {
+            let mut items: Vec<char> = ITEMS.chars().collect();
+            rng.shuffle(&mut items);
+            let badge = items.pop().unwrap();
+
+            // Disjoint items for every elf, so only the badge is common to all three
+            for pool in items.chunks(items.len() / 3).take(3) {
+                input.push_str(&rucksack(rng, pool, badge));
+                input.push('\n');
+            }
+        }
+
+        return input;
+    }
+}
+
+/// Rucksack of `pool` items whose compartments only share the first item,
+/// with the badge in one of them.
+fn rucksack(rng: &mut Rng, pool: &[char], badge: char) -> String {
+    let shared = pool[0];
+    let (first_pool, second_pool) = pool[1..].split_at(pool.len() / 2);
+    let length = rng.between(2..=16) as usize;
+
+    let mut compartments = [first_pool, second_pool].map(|pool| {
+        let mut compartment: Vec<char> = (1..length).map(|_| *rng.pick(pool)).collect();
+        compartment.push(shared);
+        rng.shuffle(&mut compartment);
+        compartment
+    });
+
+    let compartment = rng.below(2);
+    let position = rng.below(length);
+    if compartments[compartment][position] == shared {
+        compartments[compartment][(position + 1) % length] = badge;
+    } else {
+        compartments[compartment][position] = badge;
+    }
+
+    return compartments.concat().into_iter().collect();
+}
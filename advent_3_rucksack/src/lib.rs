@@ -0,0 +1,145 @@
+mod generate;
+
+use std::fmt::Display;
+
+use aoc_core::parse::numbered_lines;
+use aoc_core::{ParseError, Puzzle, Result};
+
+pub struct RucksackReorganization;
+
+impl Puzzle for RucksackReorganization {
+    const DAY: u8 = 3;
+
+    type Parsed = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(rucksacks: &Self::Parsed) -> Result<Self::Answer1> {
+        part1(rucksacks)
+    }
+
+    fn part2(rucksacks: &Self::Parsed) -> Result<Self::Answer2> {
+        part2(rucksacks)
+    }
+}
+
+/// Rucksack contents, one line per rucksack.
+fn parse(input: &str) -> Result<Vec<String>> {
+    let mut rucksacks = Vec::new();
+
+    for (line_number, line) in numbered_lines(input) {
+        if let Some(i) = line.find(|c: char| !c.is_ascii_alphabetic()) {
+            return Err(Box::new(ParseError::at_char(line_number, line, i, "an item letter 'a'-'z' or 'A'-'Z'")));
+        }
+        if line.len() % 2 != 0 {
+            return Err(Box::new(ParseError::new(line_number, line, line, "an even number of items")));
+        }
+
+        rucksacks.push(line.to_string());
+    }
+
+    return Ok(rucksacks);
+}
+
+fn part1(rucksacks: &[String]) -> Result<u32> {
+    let mut misplaced_priorities: u32 = 0;
+
+    for rucksack in rucksacks {
+        let (first, second) = rucksack.split_at(rucksack.len() / 2);
+
+        let item = match first.chars().find(|c| second.contains(*c)) {
+            Some(c) => Ok(c),
+            None => Err(Box::new(RucksackError::ItemNoMatch))
+        }?;
+
+        misplaced_priorities += get_priority(item);
+    }
+
+    return Ok(misplaced_priorities);
+}
+
+fn part2(lines: &[String]) -> Result<u32> {
+    let mut duplicated_priorities: u32 = 0;
+
+    if lines.len() < 3 {
+        return Err(Box::new(RucksackError::NotEnoughItems));
+    }
+    let groups = lines.chunks(3);
+
+    for group in groups {
+
+        let item = match group[0].chars().find(|c| group[1].contains(*c) && group[2].contains(*c)) {
+            Some(c) => Ok(c),
+            None => Err(Box::new(RucksackError::ItemNoMatch))
+        }?;
+
+        duplicated_priorities += get_priority(item);
+    }
+
+    return Ok(duplicated_priorities);
+}
+
+fn get_priority(item: char) -> u32 {
+    return match item.is_lowercase() {
+        true => item as u32 - 96,
+        false => item as u32 - 38
+    };
+}
+
+#[derive(Debug)]
+pub enum RucksackError {
+    NotEnoughItems,
+    ItemNoMatch
+}
+
+impl std::error::Error for RucksackError {}
+
+impl Display for RucksackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            RucksackError::NotEnoughItems => {
+                write!(f, "Rucksack did not contain enough items")
+            },
+            RucksackError::ItemNoMatch => {
+                write!(f, "Rucksack compartments did not contain same element")
+            }
+        }
+    }
+}
+
+/*
+--- Day 3: Rucksack Reorganization ---
+
+One Elf has the important job of loading all of the rucksacks with supplies for the jungle journey. Unfortunately, that Elf didn't quite follow the packing instructions, and so a few items now need to be rearranged.
+
+Each rucksack has two large compartments. All items of a given type are meant to go into exactly one of the two compartments. The Elf that did the packing failed to follow this rule for exactly one item type per rucksack.
+
+The Elves have made a list of all of the items currently in each rucksack (your puzzle input), but they need your help finding the errors. Every item type is identified by a single lowercase or uppercase letter (that is, a and A refer to different types of items).
+
+The list of items for each rucksack is given as characters all on a single line. A given rucksack always has the same number of items in each of its two compartments, so the first half of the characters represent items in the first compartment, while the second half of the characters represent items in the second compartment.
+
+For example, suppose you have the following list of contents from six rucksacks:
+
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+
+The first rucksack contains the items vJrwpWtwJgWrhcsFMMfFFhFp, which means its first compartment contains the items vJrwpWtwJgWr, while the second compartment contains the items hcsFMMfFFhFp. The only item type that appears in both compartments is lowercase p.
+The second rucksack's compartments contain jqHRNqRjqzjGDLGL and rsFMfFZSrLrFZsSL. The only item type that appears in both compartments is uppercase L.
+The third rucksack's compartments contain PmmdzqPrV and vPwwTWBwg; the only common item type is uppercase P.
+The fourth rucksack's compartments only share item type v.
+The fifth rucksack's compartments only share item type t.
+The sixth rucksack's compartments only share item type s.
+To help prioritize item rearrangement, every item type can be converted to a priority:
+
+Lowercase item types a through z have priorities 1 through 26.
+Uppercase item types A through Z have priorities 27 through 52.
+In the above example, the priority of the item type that appears in both compartments of each rucksack is 16 (p), 38 (L), 42 (P), 22 (v), 20 (t), and 19 (s); the sum of these is 157.
+*/
\ No newline at end of file
@@ -1,91 +1,10 @@
-use std::fs;
-use std::fmt::Display;
+use std::path::Path;
 
-type Error = Box<dyn std::error::Error>;
-type Result<T> = std::result::Result<T, Error>;
+use aoc_core::input::DEFAULT_INPUT;
 
-fn main() -> Result<()> {
-    let reader = fs::read_to_string("input.txt")?;
-    let mut duplicated_priorities: u32 = 0;
-
-    let lines: Vec<&str> = reader.lines().collect();
-    if lines.len() < 3 {
-        return Err(Box::new(RucksackError::NotEnoughItems));
-    }
-    let groups = lines.chunks(3);
-
-    for group in groups {
-
-        let index = match group[0].find(|c: char| group[1].contains(c) && group[2].contains(c)) {
-            Some(c) => Ok(c),
-            None => Err(Box::new(RucksackError::ItemNoMatch))
-        }?;
-
-        let item = group[0].chars().nth(index).unwrap();
-
-        let item_priority = match item.is_lowercase() {
-            true => item as u32 - 96,
-            false => item as u32 - 38
-        };
-
-        duplicated_priorities += item_priority;
-    }
-
-    println!("{}", duplicated_priorities);
-
-    return Ok(());
-}
-
-#[derive(Debug)]
-enum RucksackError {
-    NotEnoughItems,
-    ItemNoMatch
-}
-
-impl std::error::Error for RucksackError {}
-
-impl Display for RucksackError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match *self {
-            RucksackError::NotEnoughItems => {
-                write!(f, "Rucksack did not contain enough items")
-            },
-            RucksackError::ItemNoMatch => {
-                write!(f, "Rucksack compartments did not contain same element")
-            }
-        }
-    }
+fn main() {
+    aoc_core::cli::run_day::<advent_3_rucksack::RucksackReorganization>(Path::new(env!("CARGO_MANIFEST_DIR")).join(DEFAULT_INPUT), [
+        "Sum of item priorities",
+        "Sum of badge priorities",
+    ]);
 }
-
-/*
---- Day 3: Rucksack Reorganization ---
-
-One Elf has the important job of loading all of the rucksacks with supplies for the jungle journey. Unfortunately, that Elf didn't quite follow the packing instructions, and so a few items now need to be rearranged.
-
-Each rucksack has two large compartments. All items of a given type are meant to go into exactly one of the two compartments. The Elf that did the packing failed to follow this rule for exactly one item type per rucksack.
-
-The Elves have made a list of all of the items currently in each rucksack (your puzzle input), but they need your help finding the errors. Every item type is identified by a single lowercase or uppercase letter (that is, a and A refer to different types of items).
-
-The list of items for each rucksack is given as characters all on a single line. A given rucksack always has the same number of items in each of its two compartments, so the first half of the characters represent items in the first compartment, while the second half of the characters represent items in the second compartment.
-
-For example, suppose you have the following list of contents from six rucksacks:
-
-vJrwpWtwJgWrhcsFMMfFFhFp
-jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-PmmdzqPrVvPwwTWBwg
-wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-ttgJtRGJQctTZtZT
-CrZsJsPPZsGzwwsLwLmpwMDw
-
-The first rucksack contains the items vJrwpWtwJgWrhcsFMMfFFhFp, which means its first compartment contains the items vJrwpWtwJgWr, while the second compartment contains the items hcsFMMfFFhFp. The only item type that appears in both compartments is lowercase p.
-The second rucksack's compartments contain jqHRNqRjqzjGDLGL and rsFMfFZSrLrFZsSL. The only item type that appears in both compartments is uppercase L.
-The third rucksack's compartments contain PmmdzqPrV and vPwwTWBwg; the only common item type is uppercase P.
-The fourth rucksack's compartments only share item type v.
-The fifth rucksack's compartments only share item type t.
-The sixth rucksack's compartments only share item type s.
-To help prioritize item rearrangement, every item type can be converted to a priority:
-
-Lowercase item types a through z have priorities 1 through 26.
-Uppercase item types A through Z have priorities 27 through 52.
-In the above example, the priority of the item type that appears in both compartments of each rucksack is 16 (p), 38 (L), 42 (P), 22 (v), 20 (t), and 19 (s); the sum of these is 157.
-*/
\ No newline at end of file
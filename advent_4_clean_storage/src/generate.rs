@@ -0,0 +1,18 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::CampCleanup;
+
+/// `size` pairs of section assignments within sections 1 to 99.
+impl Generate for CampCleanup {
+    const DEFAULT_SIZE: usize = 1000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size.max(1) {
+            let [a, b, c, d] = [(); 4].map(|_| rng.between(1..=99));
+            input.push_str(&format!("{}-{},{}-{}\n", a.min(b), a.max(b), c.min(d), c.max(d)));
+        }
+
+        return input;
+    }
+}
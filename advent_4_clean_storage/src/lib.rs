@@ -0,0 +1,91 @@
+mod generate;
+
+use std::ops::RangeInclusive;
+
+use aoc_core::parse::{self, numbered_lines};
+use aoc_core::{ParseError, Puzzle, Result};
+use regex::Regex;
+
+pub struct CampCleanup;
+
+impl Puzzle for CampCleanup {
+    const DAY: u8 = 4;
+
+    type Parsed = Vec<(RangeInclusive<u32>, RangeInclusive<u32>)>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(pairs: &Self::Parsed) -> Result<Self::Answer1> {
+        part1(pairs)
+    }
+
+    fn part2(pairs: &Self::Parsed) -> Result<Self::Answer2> {
+        part2(pairs)
+    }
+}
+
+/// Section assignments of every elf pair.
+fn parse(input: &str) -> Result<Vec<(RangeInclusive<u32>, RangeInclusive<u32>)>> {
+    let regex = Regex::new(r"^(\d+)-(\d+),(\d+)-(\d+)$")?;
+    let mut pairs = Vec::new();
+
+    for (line_number, line) in numbered_lines(input) {
+        let cleaning_pair = regex.captures(line)
+            .ok_or_else(|| ParseError::new(line_number, line, line, "a pair of section ranges like '2-4,6-8'"))?;
+        let section = |i: usize| parse::number::<u32>(line_number, line, cleaning_pair.get(i).unwrap().as_str());
+
+        let first_range = section(1)?..=section(2)?;
+        let second_range = section(3)?..=section(4)?;
+
+        pairs.push((first_range, second_range));
+    }
+
+    return Ok(pairs);
+}
+
+fn part1(pairs: &[(RangeInclusive<u32>, RangeInclusive<u32>)]) -> Result<u32> {
+    let mut matches: u32 = 0;
+
+    for (first_range, second_range) in pairs {
+        let first_in_second = second_range.contains(first_range.start()) && second_range.contains(first_range.end());
+        let second_in_first = first_range.contains(second_range.start()) && first_range.contains(second_range.end());
+
+        if first_in_second || second_in_first {
+            matches += 1;
+        }
+    }
+
+    return Ok(matches);
+}
+
+fn part2(pairs: &[(RangeInclusive<u32>, RangeInclusive<u32>)]) -> Result<u32> {
+    let mut matches: u32 = 0;
+
+    for (first_range, second_range) in pairs {
+        let first_range: Vec<u32> = first_range.clone().collect();
+        let second_range: Vec<u32> = second_range.clone().collect();
+
+        let first_length = first_range.len();
+        let second_length = second_range.len();
+
+        if first_length > second_length {
+            let match_count: Vec<&u32> = first_range.iter().filter(|num| second_range.contains(num)).collect();
+            
+            if !match_count.is_empty() {
+                matches += 1;
+            }
+        }
+        else {
+            let match_count: Vec<&u32> = second_range.iter().filter(|num| first_range.contains(num)).collect();
+            if !match_count.is_empty() {
+                matches += 1;
+            }
+        }
+    }
+
+    return Ok(matches);
+}
@@ -0,0 +1,45 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::SupplyStacks;
+
+const STACKS: usize = 9;
+
+/// Nine stacks of up to eight crates and `size` moves, each taking at most
+/// the crates left on its stack.
+impl Generate for SupplyStacks {
+    const DEFAULT_SIZE: usize = 500;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let stacks: Vec<Vec<char>> = (0..STACKS)
+            .map(|_| (0..rng.between(1..=8)).map(|_| rng.char_of("ABCDEFGHIJKLMNOPQRSTUVWXYZ")).collect())
+            .collect();
+
+        let mut input = String::new();
+        let height = stacks.iter().map(|stack| stack.len()).max().unwrap();
+        for row in (0..height).rev() {
+            let cells: Vec<String> = stacks.iter().map(|stack| match stack.get(row) {
+                Some(name) => format!("[{}]", name),
+                None => String::from("   "),
+            }).collect();
+            input.push_str(&cells.join(" "));
+            input.push('\n');
+        }
+        let numbers: Vec<String> = (1..=STACKS).map(|number| format!(" {} ", number)).collect();
+        input.push_str(&numbers.join(" "));
+        input.push_str("\n\n");
+
+        let mut heights: Vec<usize> = stacks.iter().map(|stack| stack.len()).collect();
+        for _ in 0..size.max(1) {
+            let non_empty: Vec<usize> = (0..STACKS).filter(|&stack| heights[stack] > 0).collect();
+            let from = *rng.pick(&non_empty);
+            let to = (from + 1 + rng.below(STACKS - 1)) % STACKS;
+            let amount = 1 + rng.below(heights[from].min(10));
+
+            heights[from] -= amount;
+            heights[to] += amount;
+            input.push_str(&format!("move {} from {} to {}\n", amount, from + 1, to + 1));
+        }
+
+        return input;
+    }
+}
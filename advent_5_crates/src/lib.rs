@@ -0,0 +1,201 @@
+mod generate;
+
+use aoc_core::parse::{self, numbered_lines};
+use aoc_core::{ParseError, Puzzle, Result};
+use regex::Regex;
+
+#[derive(Clone)]
+pub struct CrateStack {
+    names: Vec<char>
+}
+
+/// Crane instruction with zero-based stack indexes.
+pub struct Move {
+    amount: usize,
+    from: usize,
+    to: usize
+}
+
+pub struct Supplies {
+    stacks: Vec<CrateStack>,
+    moves: Vec<Move>
+}
+
+impl CrateStack {
+    pub fn first(&self) -> Option<char> {
+        self.names.last().copied()
+    }
+
+    pub fn pop(&mut self, amount: usize) -> Vec<char> {
+        if self.names.len() < amount {
+            panic!("Not correct amount");
+        }
+
+        let mut popped_crates: Vec<char> = Vec::new();
+        for _ in 0..amount {
+            popped_crates.push(self.names.pop().unwrap());
+        }
+
+        popped_crates.reverse();
+        return popped_crates;
+    }
+
+    pub fn push(&mut self, crate_names: &mut Vec<char>) {
+        self.names.append(crate_names);
+    }
+}
+
+pub struct SupplyStacks;
+
+impl Puzzle for SupplyStacks {
+    const DAY: u8 = 5;
+
+    type Parsed = Supplies;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(supplies: &Self::Parsed) -> Result<Self::Answer1> {
+        part1(supplies)
+    }
+
+    fn part2(supplies: &Self::Parsed) -> Result<Self::Answer2> {
+        part2(supplies)
+    }
+}
+
+fn parse(input: &str) -> Result<Supplies> {
+    let mut crate_stacks: Vec<CrateStack> = Vec::new();
+    let mut moves: Vec<Move> = Vec::new();
+
+    let mut crate_inputs: Vec<&str> = Vec::new();
+    let re = Regex::new(r"(\d+)")?;
+    let arrangement_re = Regex::new(r"^move (\d+) from (\d+) to (\d+)$")?;
+
+    for (line_number, line) in numbered_lines(input) {
+        if line.contains('[') && line.contains("]") {
+            crate_inputs.push(line);
+        } else if line.starts_with(" 1") {
+            for cap in re.captures_iter(line) {
+                let position = cap.get(1).unwrap().start();
+
+                let names: Vec<char> = crate_inputs.iter()
+                                 .rev()
+                                .filter_map(|inp| inp.chars().nth(position))
+                                .filter(|inp| inp != &' ')
+                                .collect();
+
+                
+                let crates = CrateStack {
+                    names
+                };
+                crate_stacks.push(crates);
+            }
+        } else if line.starts_with("move") {
+            let captures = arrangement_re.captures(line)
+                .ok_or_else(|| ParseError::new(line_number, line, line, "a move like 'move 1 from 2 to 1'"))?;
+            let stack = |i: usize| -> std::result::Result<usize, ParseError> {
+                let token = captures.get(i).unwrap().as_str();
+                match parse::number::<usize>(line_number, line, token)? {
+                    n if (1..=crate_stacks.len()).contains(&n) => Ok(n - 1),
+                    _ => Err(ParseError::new(line_number, line, token, format!("a stack number from 1 to {}", crate_stacks.len()))),
+                }
+            };
+
+            moves.push(Move {
+                amount: parse::number(line_number, line, captures.get(1).unwrap().as_str())?,
+                from: stack(2)?,
+                to: stack(3)?
+            });
+        } else if !line.is_empty() {
+            return Err(Box::new(ParseError::new(line_number, line, line, "a crate row, the stack numbers or a move")));
+        }
+    }
+
+    return Ok(Supplies { stacks: crate_stacks, moves });
+}
+
+/// CrateMover 9000 moves crates one at a time.
+fn part1(supplies: &Supplies) -> Result<String> {
+    return rearrange(supplies, true);
+}
+
+/// CrateMover 9001 moves several crates at once, keeping their order.
+fn part2(supplies: &Supplies) -> Result<String> {
+    return rearrange(supplies, false);
+}
+
+fn rearrange(supplies: &Supplies, one_at_a_time: bool) -> Result<String> {
+    let mut crate_stacks = supplies.stacks.clone();
+
+    for &Move { amount, from, to } in &supplies.moves {
+        let from_crate = crate_stacks.get_mut(from).unwrap();
+        let mut popped_crates = from_crate.pop(amount);
+        if one_at_a_time {
+            popped_crates.reverse();
+        }
+
+        let to_crate = crate_stacks.get_mut(to).unwrap();
+        to_crate.push(&mut popped_crates);
+    }
+
+    let top_crates: String = crate_stacks.iter().filter_map(|stack| stack.first()).collect();
+    return Ok(top_crates);
+}
+
+/*
+
+--- Day 5: Supply Stacks ---
+The expedition can depart as soon as the final supplies have been unloaded from the ships. Supplies are stored in stacks of marked crates, but because the needed supplies are buried under many other crates, the crates need to be rearranged.
+
+The ship has a giant cargo crane capable of moving crates between stacks. To ensure none of the crates get crushed or fall over, the crane operator will rearrange them in a series of carefully-planned steps. After the crates are rearranged, the desired crates will be at the top of each stack.
+
+The Elves don't want to interrupt the crane operator during this delicate procedure, but they forgot to ask her which crate will end up where, and they want to be ready to unload them as soon as possible so they can embark.
+
+They do, however, have a drawing of the starting stacks of crates and the rearrangement procedure (your puzzle input). For example:
+
+    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+In this example, there are three stacks of crates. Stack 1 contains two crates: crate Z is on the bottom, and crate N is on top. Stack 2 contains three crates; from bottom to top, they are crates M, C, and D. Finally, stack 3 contains a single crate, P.
+
+Then, the rearrangement procedure is given. In each step of the procedure, a quantity of crates is moved from one stack to a different stack. In the first step of the above rearrangement procedure, one crate is moved from stack 2 to stack 1, resulting in this configuration:
+
+[D]        
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+In the second step, three crates are moved from stack 1 to stack 3. Crates are moved one at a time, so the first crate to be moved (D) ends up below the second and third crates:
+
+        [Z]
+        [N]
+    [C] [D]
+    [M] [P]
+ 1   2   3
+Then, both crates are moved from stack 2 to stack 1. Again, because crates are moved one at a time, crate C ends up below crate M:
+
+        [Z]
+        [N]
+[M]     [D]
+[C]     [P]
+ 1   2   3
+Finally, one crate is moved from stack 1 to stack 2:
+
+        [Z]
+        [N]
+        [D]
+[C] [M] [P]
+ 1   2   3
+The Elves just need to know which crate will end up on top of each stack; in this example, the top crates are C in stack 1, M in stack 2, and Z in stack 3, so you should combine these together and give the Elves the message CMZ.
+
+After the rearrangement procedure completes, what crate ends up on top of each stack?
+*/
\ No newline at end of file
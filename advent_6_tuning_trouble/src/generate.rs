@@ -0,0 +1,23 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::TuningTrouble;
+
+/// Datastream of `size` letters with fourteen different letters in a row
+/// somewhere in it, so both markers exist.
+impl Generate for TuningTrouble {
+    const DEFAULT_SIZE: usize = 4096;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(14);
+        // Few distinct letters make markers rare before the planted one
+        let letters: String = (0..6).map(|_| rng.char_of("abcdefghijklmnopqrstuvwxyz")).collect();
+        let mut datastream: Vec<char> = (0..size).map(|_| rng.char_of(&letters)).collect();
+
+        let mut marker: Vec<char> = "abcdefghijklmnopqrstuvwxyz".chars().collect();
+        rng.shuffle(&mut marker);
+        let start = rng.below(size - 13);
+        datastream[start..start + 14].copy_from_slice(&marker[..14]);
+
+        return datastream.into_iter().collect::<String>() + "\n";
+    }
+}
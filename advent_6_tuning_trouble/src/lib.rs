@@ -0,0 +1,79 @@
+mod generate;
+
+use std::collections::HashSet;
+
+use aoc_core::parse::numbered_lines;
+use aoc_core::{verbose, ParseError, Puzzle, Result};
+
+pub struct TuningTrouble;
+
+impl Puzzle for TuningTrouble {
+    const DAY: u8 = 6;
+
+    type Parsed = Vec<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(communication_packet: &Self::Parsed) -> Result<Self::Answer1> {
+        part1(communication_packet)
+    }
+
+    fn part2(communication_packet: &Self::Parsed) -> Result<Self::Answer2> {
+        part2(communication_packet)
+    }
+}
+
+/// Communication packet characters, without the trailing newline.
+fn parse(input: &str) -> Result<Vec<char>> {
+    let mut lines = numbered_lines(input.trim_end());
+    let Some((line_number, line)) = lines.next() else {
+        return Err(Box::new(ParseError::end_of_input(input, "a datastream line")));
+    };
+
+    if let Some(i) = line.find(|c: char| !c.is_ascii_lowercase()) {
+        return Err(Box::new(ParseError::at_char(line_number, line, i, "a lowercase letter")));
+    }
+    if let Some((line_number, line)) = lines.next() {
+        return Err(Box::new(ParseError::new(line_number, line, line, "a single datastream line")));
+    }
+
+    return Ok(line.chars().collect());
+}
+
+fn part1(communication_packet: &[char]) -> Result<usize> {
+    return find_marker(communication_packet, 4);
+}
+
+fn part2(communication_packet: &[char]) -> Result<usize> {
+    return find_marker(communication_packet, 14);
+}
+
+/// Number of characters processed once the last `length` characters are all different.
+fn find_marker(communication_packet: &[char], length: usize) -> Result<usize> {
+    for (index, window) in communication_packet.windows(length).enumerate() {
+        let uniq: HashSet<&char> = window.iter().collect();
+
+        if uniq.len() == length {
+            let identifier: String = window.iter().collect();
+            verbose!("Marker identifiers are: {}", identifier);
+            return Ok(index + length);
+        }
+    }
+
+    return Err(Box::new(MarkerNotFound));
+}
+
+#[derive(Debug)]
+pub struct MarkerNotFound;
+
+impl std::error::Error for MarkerNotFound {}
+
+impl std::fmt::Display for MarkerNotFound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Communication packet does not contain a start-of-message marker")
+    }
+}
@@ -0,0 +1,71 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::NoSpaceLeftOnDevice;
+
+/// Letters for file and directory names, without 'c' and 'd' so no name
+/// contains "cd".
+const NAME_LETTERS: &str = "abefghijklmnopqrstuvwxyz";
+
+/// Terminal output exploring a tree of `size` directories depth first,
+/// using at most 40 000 000 of the disk like the real input does.
+impl Generate for NoSpaceLeftOnDevice {
+    const DEFAULT_SIZE: usize = 180;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let mut children: Vec<Vec<usize>> = vec![Vec::new(); size];
+        let mut names = vec![String::from("/")];
+        for directory in 1..size {
+            let parent = rng.below(directory);
+            let siblings: Vec<&str> = children[parent].iter().map(|&sibling| names[sibling].as_str()).collect();
+            names.push(unique_name(rng, &siblings, false));
+            children[parent].push(directory);
+        }
+
+        let file_budget = 40_000_000 / size as i64;
+        let mut input = String::new();
+        list(rng, 0, &children, &names, file_budget, &mut input);
+
+        return input;
+    }
+}
+
+fn list(rng: &mut Rng, directory: usize, children: &[Vec<usize>], names: &[String], file_budget: i64, input: &mut String) {
+    input.push_str(&format!("$ cd {}\n$ ls\n", names[directory]));
+
+    let mut taken: Vec<String> = children[directory].iter().map(|&child| names[child].clone()).collect();
+    let mut listing: Vec<String> = taken.iter().map(|name| format!("dir {}", name)).collect();
+    let mut budget = file_budget;
+    for _ in 0..rng.between(0..=4) {
+        let length = rng.between(1000..=(budget / 2).max(1000));
+        budget -= length;
+        let name = unique_name(rng, &taken.iter().map(String::as_str).collect::<Vec<_>>(), true);
+        listing.push(format!("{} {}", length, name));
+        taken.push(name);
+    }
+    rng.shuffle(&mut listing);
+    for entry in listing {
+        input.push_str(&entry);
+        input.push('\n');
+    }
+
+    for &child in &children[directory] {
+        list(rng, child, children, names, file_budget, input);
+        input.push_str("$ cd ..\n");
+    }
+}
+
+/// Name not in `taken`, sometimes with an extension for files.
+fn unique_name(rng: &mut Rng, taken: &[&str], file: bool) -> String {
+    loop {
+        let mut name: String = (0..rng.between(1..=8)).map(|_| rng.char_of(NAME_LETTERS)).collect();
+        if file && rng.chance(0.5) {
+            name.push('.');
+            name.extend((0..3).map(|_| rng.char_of(NAME_LETTERS)));
+        }
+
+        if !taken.contains(&name.as_str()) {
+            return name;
+        }
+    }
+}
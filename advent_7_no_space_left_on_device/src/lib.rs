@@ -0,0 +1,220 @@
+mod generate;
+
+use aoc_core::parse::{self, numbered_lines};
+use aoc_core::{trace, verbose, DayConfig, ParseError, Puzzle, Result};
+
+const COMMAND_SYMBOL: &str = "$";
+const TOTAL_SYSTEM_SIZE: usize = 70000000;
+const SPACE_NEEDED_FOR_UPDATE: usize = 30000000;
+
+#[derive(Debug)]
+struct File {
+    _filename: String,
+    length: usize,
+}
+
+#[derive(Debug)]
+struct Directory {
+    name: String,
+    // List of references to directories
+    directories: Vec<usize>,
+    // List of references to files
+    files: Vec<usize>,
+}
+
+pub struct FileSystem {
+    directories: Vec<Directory>,
+    files: Vec<File>,
+    total_system_size: usize,
+    space_needed_for_update: usize,
+}
+
+pub struct NoSpaceLeftOnDevice;
+
+impl Puzzle for NoSpaceLeftOnDevice {
+    const DAY: u8 = 7;
+
+    type Parsed = FileSystem;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(file_system: &Self::Parsed) -> Result<Self::Answer1> {
+        part1(file_system)
+    }
+
+    fn part2(file_system: &Self::Parsed) -> Result<Self::Answer2> {
+        part2(file_system)
+    }
+
+    fn configure(file_system: &mut Self::Parsed, config: &DayConfig) -> Result<()> {
+        file_system.total_system_size = config.get("total_system_size", TOTAL_SYSTEM_SIZE)?;
+        file_system.space_needed_for_update = config.get("space_needed_for_update", SPACE_NEEDED_FOR_UPDATE)?;
+        Ok(())
+    }
+}
+
+fn parse(input: &str) -> Result<FileSystem> {
+    let mut directories = vec![Directory {
+        name: String::from(""),
+        directories: Vec::new(),
+        files: Vec::new()
+    }];
+    let mut files: Vec<File> = Vec::new();
+
+    let mut current_directory_index: usize = 0;
+
+    for (line_number, line) in numbered_lines(input) {
+        let line_input = line;
+
+        if line_input.starts_with(COMMAND_SYMBOL) {
+            let line_input = line_input.replace(COMMAND_SYMBOL, " ");
+            let line_input = line_input.trim();
+
+            
+            if line_input.starts_with("cd") {
+                let path = line_input.replace("cd", "");
+                let path = path.trim();
+
+                if path == "/" {
+                    current_directory_index = 0;
+                }
+                else if path == ".." {
+                    for (i, dir) in directories.iter().enumerate() {
+                        if dir.directories.contains(&current_directory_index) {
+                            current_directory_index = i;
+                        }
+                    }
+                }
+                else {
+                    let mut dir_exists = false;
+                    for dir_index in &directories[current_directory_index].directories {
+                        if directories[*dir_index].name == path {
+                            current_directory_index = *dir_index;
+                            dir_exists = true;
+                            break;
+                        }
+                    }
+    
+                    if !dir_exists {
+                        let dir = Directory {
+                            name: String::from(path),
+                            directories: Vec::new(),
+                            files: Vec::new()
+                        };
+                        directories.push(dir);
+                        let new_dir_index = directories.len() - 1;
+
+                        directories[current_directory_index].directories.push(new_dir_index);
+                        current_directory_index = new_dir_index;
+                    }
+                }
+            } else if line_input != "ls" {
+                let command = line[COMMAND_SYMBOL.len()..].trim();
+                return Err(Box::new(ParseError::new(line_number, line, command, "a 'cd' or 'ls' command")));
+            }
+        } else if !line_input.starts_with("dir ") {
+            let (size, filename) = line_input.split_once(' ')
+                .ok_or_else(|| ParseError::new(line_number, line, line, "a listing like '14848514 b.txt' or 'dir a'"))?;
+            let length = parse::number(line_number, line, size)?;
+
+            let file = File {
+                _filename: String::from(filename),
+                length
+            };
+
+            files.push(file);
+            directories[current_directory_index].files.push(files.len() - 1);
+        }
+    }
+
+    trace!("Files: ");
+    for file in &files {
+        trace!("{:?}", file);
+    }
+
+    trace!("Directories: ");
+    for dir in &directories {
+        trace!("{:?}", dir);
+    }
+
+    return Ok(FileSystem {
+        directories,
+        files,
+        total_system_size: TOTAL_SYSTEM_SIZE,
+        space_needed_for_update: SPACE_NEEDED_FOR_UPDATE,
+    });
+}
+
+fn get_directory_sizes(file_system: &FileSystem) -> Vec<usize> {
+    let mut directory_sizes = vec![0; file_system.directories.len()];
+    for (i, dir) in file_system.directories.iter().enumerate().rev() {
+        for child_dir in &dir.directories {
+            directory_sizes[i] += directory_sizes[*child_dir];
+        }        
+
+        for file_index in &dir.files {
+            directory_sizes[i] += file_system.files[*file_index].length;
+        }
+    }
+
+    return directory_sizes;
+}
+
+fn part1(file_system: &FileSystem) -> Result<usize> {
+    let directory_sizes = get_directory_sizes(file_system);
+
+    verbose!("Directories less than 100 000:");
+
+    let mut combined_small_dir_size: usize = 0;
+    for (i, small_dir) in directory_sizes.iter().enumerate() {
+        if *small_dir < 100000 {
+            verbose!("{:?}", file_system.directories[i]);
+            combined_small_dir_size += *small_dir;
+        }
+    }
+
+    return Ok(combined_small_dir_size);
+}
+
+fn part2(file_system: &FileSystem) -> Result<usize> {
+    let directory_sizes = get_directory_sizes(file_system);
+
+    let used_system_space: usize = directory_sizes[0];
+
+    let free_space = file_system.total_system_size.saturating_sub(used_system_space);
+    let needed_space: usize = file_system.space_needed_for_update.saturating_sub(free_space);
+
+    verbose!("Total directories size is: {}", directory_sizes[0]);
+
+    let mut directory_tuple = Vec::new();
+    for (i, dir) in file_system.directories.iter().enumerate() {
+        directory_tuple.push((i, dir.name.clone(), directory_sizes[i]));
+    }
+
+    directory_tuple.sort_by_key(|a| a.2);
+
+    let dir_to_delete = directory_tuple.iter().find(|&t| t.2 >= needed_space);
+
+    match dir_to_delete {
+        Some(d) => {
+            verbose!("Directory to delete: {}", d.1);
+            return Ok(d.2);
+        },
+        None => return Err(Box::new(DirectoryNotFound))
+    };
+}
+
+#[derive(Debug)]
+pub struct DirectoryNotFound;
+
+impl std::error::Error for DirectoryNotFound {}
+
+impl std::fmt::Display for DirectoryNotFound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Directory large enough to free up space was not found")
+    }
+}
@@ -0,0 +1,19 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::TreetopTreeHouse;
+
+/// Square forest of `size` by `size` tree heights.
+impl Generate for TreetopTreeHouse {
+    const DEFAULT_SIZE: usize = 99;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let mut input = String::new();
+        for _ in 0..size {
+            input.extend((0..size).map(|_| rng.char_of("0123456789")));
+            input.push('\n');
+        }
+
+        return input;
+    }
+}
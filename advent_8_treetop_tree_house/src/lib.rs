@@ -0,0 +1,112 @@
+mod generate;
+
+use aoc_core::geometry::Point2;
+use aoc_core::grid::{Grid, Position};
+use aoc_core::{trace, Puzzle, Result};
+
+pub struct TreetopTreeHouse;
+
+impl Puzzle for TreetopTreeHouse {
+    const DAY: u8 = 8;
+
+    type Parsed = Grid<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(grid: &Self::Parsed) -> Result<Self::Answer1> {
+        part1(grid)
+    }
+
+    fn part2(grid: &Self::Parsed) -> Result<Self::Answer2> {
+        part2(grid)
+    }
+}
+
+fn part1(grid: &Grid<u32>) -> Result<u32> {
+    let mut visible_count: u32 = 0;
+
+    for (position, &height) in grid.iter() {
+        // Edge trees have an empty line of sight, so they are always visible
+        let is_visible = lines_of_sight(grid, position).iter().any(|line| line.iter().all(|&tree| tree < height));
+
+        if is_visible {
+            trace!("is visible value {}, position: {}:{}", height, position.y, position.x);
+            visible_count += 1;
+        }
+    }
+
+    return Ok(visible_count);
+}
+
+fn part2(grid: &Grid<u32>) -> Result<u32> {
+    let mut highest_scenic_score = 0;
+
+    for (position, &height) in grid.iter() {
+        let spot_scenic_count: u32 = lines_of_sight(grid, position).iter()
+            .map(|line| match line.iter().position(|&tree| tree >= height) {
+                Some(blocking) => blocking as u32 + 1,
+                None => line.len() as u32,
+            })
+            .product();
+
+        if spot_scenic_count > highest_scenic_score {
+            highest_scenic_score = spot_scenic_count;
+        }
+    }
+
+    return Ok(highest_scenic_score);
+}
+
+/// Tree heights seen from `position` looking up, down, left and right,
+/// nearest tree first.
+fn lines_of_sight(grid: &Grid<u32>, Point2 { x, y }: Position) -> [Vec<u32>; 4] {
+    let heights = |trees: &mut dyn Iterator<Item = (Position, &u32)>| trees.map(|(_, &height)| height).collect::<Vec<u32>>();
+
+    return [
+        heights(&mut grid.column(x).rev().filter(|&(tree, _)| tree.y < y)),
+        heights(&mut grid.column(x).filter(|&(tree, _)| tree.y > y)),
+        heights(&mut grid.row(y).rev().filter(|&(tree, _)| tree.x < x)),
+        heights(&mut grid.row(y).filter(|&(tree, _)| tree.x > x)),
+    ];
+}
+
+fn parse(input: &str) -> Result<Grid<u32>> {
+    let grid = Grid::parse_rectangle(input, "trees", "a tree height digit", |c| c.to_digit(10))?;
+    return Ok(grid);
+}
+
+
+/*
+--- Day 8: Treetop Tree House ---
+The expedition comes across a peculiar patch of tall trees all planted carefully in a grid. The Elves explain that a previous expedition planted these trees as a reforestation effort. Now, they're curious if this would be a good location for a tree house.
+
+First, determine whether there is enough tree cover here to keep a tree house hidden. To do this, you need to count the number of trees that are visible from outside the grid when looking directly along a row or column.
+
+The Elves have already launched a quadcopter to generate a map with the height of each tree (your puzzle input). For example:
+
+30373
+25512
+65332
+33549
+35390
+Each tree is represented as a single digit whose value is its height, where 0 is the shortest and 9 is the tallest.
+
+A tree is visible if all of the other trees between it and an edge of the grid are shorter than it. Only consider trees in the same row or column; that is, only look up, down, left, or right from any given tree.
+
+All of the trees around the edge of the grid are visible - since they are already on the edge, there are no trees to block the view. In this example, that only leaves the interior nine trees to consider:
+
+The top-left 5 is visible from the left and top. (It isn't visible from the right or bottom since other trees of height 5 are in the way.)
+The top-middle 5 is visible from the top and right.
+The top-right 1 is not visible from any direction; for it to be visible, there would need to only be trees of height 0 between it and an edge.
+The left-middle 5 is visible, but only from the right.
+The center 3 is not visible from any direction; for it to be visible, there would need to be only trees of at most height 2 between it and an edge.
+The right-middle 3 is visible from the right.
+In the bottom row, the middle 5 is visible, but the 3 and 4 are not.
+With 16 trees visible on the edge and another 5 visible in the interior, a total of 21 trees are visible in this arrangement.
+
+Consider your map; how many trees are visible from outside the grid?
+*/
\ No newline at end of file
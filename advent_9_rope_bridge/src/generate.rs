@@ -0,0 +1,17 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::RopeBridge;
+
+/// `size` head motions of 1 to 20 steps.
+impl Generate for RopeBridge {
+    const DEFAULT_SIZE: usize = 2000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size.max(1) {
+            input.push_str(&format!("{} {}\n", rng.char_of("URDL"), rng.between(1..=20)));
+        }
+
+        return input;
+    }
+}
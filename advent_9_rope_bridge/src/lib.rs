@@ -0,0 +1,121 @@
+mod generate;
+mod visualize;
+
+use std::collections::HashSet;
+
+use aoc_core::geometry::{Direction, Point2};
+use aoc_core::parse::{self, numbered_lines};
+use aoc_core::{trace, DayConfig, ParseError, Puzzle, Result};
+
+/// Number of knots in the long rope of part 2, head and tail included.
+const KNOTS_COUNT: usize = 10;
+
+pub struct Motions {
+    motions: Vec<(Direction, usize)>,
+    knots_count: usize,
+}
+
+pub struct RopeBridge;
+
+impl Puzzle for RopeBridge {
+    const DAY: u8 = 9;
+
+    type Parsed = Motions;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(motions: &Self::Parsed) -> Result<Self::Answer1> {
+        part1(&motions.motions)
+    }
+
+    fn part2(motions: &Self::Parsed) -> Result<Self::Answer2> {
+        part2(&motions.motions, motions.knots_count)
+    }
+
+    fn configure(motions: &mut Self::Parsed, config: &DayConfig) -> Result<()> {
+        motions.knots_count = config.get_at_least("knots", KNOTS_COUNT, 2)?;
+        Ok(())
+    }
+}
+
+/// Head motions as direction and step count.
+fn parse(input: &str) -> Result<Motions> {
+    let mut motions = Vec::new();
+
+    for (line_number, line) in numbered_lines(input) {
+        let (direction, steps) = line.split_once(' ')
+            .ok_or_else(|| ParseError::new(line_number, line, line, "a motion like 'R 4'"))?;
+        let steps_count = parse::number::<usize>(line_number, line, steps)?;
+
+        let direction = match direction {
+            "U" => Direction::North,
+            "R" => Direction::East,
+            "D" => Direction::South,
+            "L" => Direction::West,
+            _ => return Err(Box::new(ParseError::new(line_number, line, direction, "a direction 'U', 'R', 'D' or 'L'"))),
+        };
+        motions.push((direction, steps_count));
+    }
+
+    return Ok(Motions { motions, knots_count: KNOTS_COUNT });
+}
+
+fn part1(motions: &[(Direction, usize)]) -> Result<usize> {
+    return Ok(simulate_rope(motions, 2));
+}
+
+fn part2(motions: &[(Direction, usize)], knots_count: usize) -> Result<usize> {
+    return Ok(simulate_rope(motions, knots_count));
+}
+
+/// Pulls a rope of `knots_count` knots by its head through the motions,
+/// counting the positions its tail visits.
+fn simulate_rope(motions: &[(Direction, usize)], knots_count: usize) -> usize {
+    let mut rope = Rope::new(knots_count);
+    let mut visited_nodes: HashSet<Point2> = HashSet::from([rope.tail()]);
+
+    for &(direction, steps_count) in motions {
+        for _ in 0..steps_count {
+            rope.pull(direction);
+
+            trace!("Tail position: {:?}", rope.tail());
+            visited_nodes.insert(rope.tail());
+        }
+    }
+
+    return visited_nodes.len();
+}
+
+/// Knots of a rope from its head to its tail, all starting on the origin.
+struct Rope {
+    knots: Vec<Point2>,
+}
+
+impl Rope {
+    fn new(knots_count: usize) -> Rope {
+        Rope { knots: vec![Point2::origin(); knots_count] }
+    }
+
+    fn tail(&self) -> Point2 {
+        self.knots[self.knots.len() - 1]
+    }
+
+    /// Moves the head one step, the other knots following it.
+    fn pull(&mut self, direction: Direction) {
+        let knots = &mut self.knots;
+        knots[0] += direction.offset();
+
+        for i in 1..knots.len() {
+            // A knot stays put while it touches the one ahead, otherwise
+            // it steps towards it, diagonally when not in the same line
+            if knots[i].chebyshev(knots[i - 1]) > 1 {
+                let step = (knots[i - 1] - knots[i]).signum();
+                knots[i] += step;
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+
+use aoc_core::geometry::{Direction, Point2};
+use aoc_core::grid::Bounds;
+use aoc_core::visualize::{Frames, Visualize};
+use aoc_core::Result;
+
+use crate::{Motions, Rope, RopeBridge};
+
+const WIDTH: i32 = 40;
+const HEIGHT: i32 = 20;
+/// Closest the head gets to the window's edge before it scrolls.
+const MARGIN: i32 = 3;
+
+/// The configured rope one head step per frame, drawn like the puzzle does
+/// (`H`, `1`, `2`... or `T` for a two knot rope, `s` for the start and `#`
+/// for the positions the tail visited) in a window following the head.
+impl Visualize for RopeBridge {
+    fn visualize(motions: &Motions, frames: &mut Frames) -> Result<()> {
+        let mut rope = Rope::new(motions.knots_count);
+        let mut visited = HashSet::from([rope.tail()]);
+        let mut window = Bounds { min: Point2::new(-WIDTH / 2, -HEIGHT / 2), max: Point2::new(WIDTH / 2 - 1, HEIGHT / 2 - 1) };
+
+        if !frames.push("Start", draw(&rope, &visited, window)) {
+            return Ok(());
+        }
+
+        for &(direction, steps_count) in &motions.motions {
+            for step in 1..=steps_count {
+                rope.pull(direction);
+                visited.insert(rope.tail());
+                follow(&mut window, rope.knots[0]);
+
+                let caption = format!("== {} {} == step {}, tail visited {} positions", letter(direction), steps_count, step, visited.len());
+                if !frames.push(caption, draw(&rope, &visited, window)) {
+                    return Ok(());
+                }
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+fn letter(direction: Direction) -> char {
+    match direction {
+        Direction::North => 'U',
+        Direction::East => 'R',
+        Direction::South => 'D',
+        Direction::West => 'L',
+    }
+}
+
+/// Scrolls the window just enough to keep the head `MARGIN` away from its edges.
+fn follow(window: &mut Bounds, head: Point2) {
+    let shift = |position: i32, min: i32, max: i32| (position - (min + MARGIN)).min(0) + (position - (max - MARGIN)).max(0);
+    let offset = Point2::new(shift(head.x, window.min.x, window.max.x), shift(head.y, window.min.y, window.max.y));
+
+    window.min += offset;
+    window.max += offset;
+}
+
+fn draw(rope: &Rope, visited: &HashSet<Point2>, window: Bounds) -> String {
+    let knots_count = rope.knots.len();
+
+    return window.render(|position| match rope.knots.iter().position(|&knot| knot == position) {
+        Some(0) => 'H',
+        Some(1) if knots_count == 2 => 'T',
+        Some(i) => char::from_digit(i as u32, 36).unwrap_or('*'),
+        None if position == Point2::origin() => 's',
+        None if visited.contains(&position) => '#',
+        None => '.',
+    });
+}
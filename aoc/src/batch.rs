@@ -0,0 +1,206 @@
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use aoc_core::format::part_json;
+use aoc_core::input::{self, input_files};
+use aoc_core::puzzle::catch_panic;
+use aoc_core::{read_input, Answer, Config, Format, Part, Result, Unsolved};
+
+use crate::cli::RunArgs;
+use crate::days::{self, Day};
+use crate::report::format_duration;
+
+/// One part solved on one input of the batch.
+struct BatchPart {
+    part: Part,
+    outcome: Outcome,
+    elapsed: Duration,
+}
+
+enum Outcome {
+    Answer(Answer),
+    Unsolved,
+    Error(String),
+    Panic(String),
+}
+
+struct BatchInput {
+    path: PathBuf,
+    parts: Vec<BatchPart>,
+}
+
+impl BatchInput {
+    fn status(&self) -> &'static str {
+        let outcomes = || self.parts.iter().map(|part| &part.outcome);
+
+        if outcomes().any(|outcome| matches!(outcome, Outcome::Panic(_))) {
+            "PANIC"
+        } else if outcomes().any(|outcome| matches!(outcome, Outcome::Error(_))) {
+            "ERROR"
+        } else {
+            "ok"
+        }
+    }
+
+    fn failed(&self) -> bool {
+        self.status() != "ok"
+    }
+
+    fn name(&self) -> String {
+        match self.path.file_name() {
+            Some(name) => name.to_string_lossy().into_owned(),
+            None => input::display_name(&self.path),
+        }
+    }
+}
+
+/// Solves one day on every file of a directory, each part separately so a
+/// panicking part still leaves the other answer, and tabulates the answers.
+/// Fails when any input errored or panicked.
+pub fn run(args: &RunArgs) -> Result<()> {
+    let day = args.day.and_then(days::find).expect("Day is validated by the argument parser");
+    let dir = args.input.clone().expect("Input directory is validated by the argument parser");
+    let config = args.config()?;
+    let parts = args.parts();
+
+    let inputs: Vec<BatchInput> = input_files(&dir)?.into_iter()
+        .map(|path| solve_input(day, path, &parts, &config))
+        .collect();
+
+    match args.format {
+        Format::Text => print_table(day, &dir, &inputs, &parts),
+        Format::Json => print_json(day, &inputs),
+    }
+
+    let failed = inputs.iter().filter(|input| input.failed()).count();
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{} of {} inputs failed", failed, inputs.len()).into()),
+    }
+}
+
+fn solve_input(day: &'static Day, path: PathBuf, parts: &[Part], config: &Config) -> BatchInput {
+    let input = match read_input(&path) {
+        Ok(input) => input,
+        Err(e) => {
+            let parts = parts.iter().map(|&part| BatchPart { part, outcome: Outcome::Error(e.to_string()), elapsed: Duration::ZERO });
+            return BatchInput { parts: parts.collect(), path };
+        }
+    };
+
+    let parts = parts.iter().map(|&part| {
+        match catch_panic(|| day.solver.run(&input, &[part], config)) {
+            Ok(Ok(run)) => {
+                let part_run = run.parts.into_iter().next().expect("Solver runs every requested part");
+                let outcome = match part_run.answer {
+                    Ok(answer) => Outcome::Answer(answer),
+                    Err(e) if e.is::<Unsolved>() => Outcome::Unsolved,
+                    Err(e) => Outcome::Error(e.to_string()),
+                };
+                BatchPart { part, outcome, elapsed: part_run.elapsed }
+            }
+            Ok(Err(e)) => BatchPart { part, outcome: Outcome::Error(e.to_string()), elapsed: Duration::ZERO },
+            Err(message) => BatchPart { part, outcome: Outcome::Panic(message), elapsed: Duration::ZERO },
+        }
+    });
+
+    BatchInput { parts: parts.collect(), path }
+}
+
+fn answer_cell(outcome: &Outcome) -> String {
+    match outcome {
+        Outcome::Answer(answer) => {
+            let text = answer.to_string();
+            match text.lines().count() {
+                0 | 1 => text,
+                lines => format!("<{} lines>", lines),
+            }
+        }
+        Outcome::Unsolved => String::from("unsolved"),
+        Outcome::Error(_) => String::from("error"),
+        Outcome::Panic(_) => String::from("panic"),
+    }
+}
+
+fn time_cell(part: &BatchPart) -> String {
+    match part.outcome {
+        Outcome::Error(_) | Outcome::Panic(_) => String::from("-"),
+        _ => format_duration(part.elapsed),
+    }
+}
+
+fn print_table(day: &Day, dir: &Path, inputs: &[BatchInput], parts: &[Part]) {
+    let cells: Vec<Vec<String>> = inputs.iter().map(|input| {
+        input.parts.iter()
+            .flat_map(|part| [answer_cell(&part.outcome), time_cell(part)])
+            .collect()
+    }).collect();
+
+    let mut headers = vec![String::from("Input")];
+    for part in parts {
+        headers.push(format!("Part {}", part));
+        headers.push(String::from("Time"));
+    }
+    headers.push(String::from("Status"));
+
+    let rows: Vec<Vec<String>> = inputs.iter().zip(cells).map(|(input, cells)| {
+        let mut row = vec![input.name()];
+        row.extend(cells);
+        row.push(input.status().to_string());
+        row
+    }).collect();
+
+    let widths: Vec<usize> = (0..headers.len())
+        .map(|column| rows.iter().chain([&headers]).map(|row| row[column].chars().count()).max().unwrap_or(0))
+        .collect();
+    let format_row = |row: &[String]| {
+        row.iter().zip(&widths).map(|(cell, &width)| format!("{:<width$}", cell)).collect::<Vec<_>>().join("  ").trim_end().to_string()
+    };
+
+    let highlight = io::stdout().is_terminal();
+    println!("Day {} {}, {} inputs in {}", day.number, day.title, inputs.len(), dir.display());
+    println!("{}", format_row(&headers));
+    for (input, row) in inputs.iter().zip(&rows) {
+        if input.failed() && highlight {
+            println!("\x1b[1;31m{}\x1b[0m", format_row(row));
+        } else {
+            println!("{}", format_row(row));
+        }
+    }
+
+    let failures: Vec<String> = inputs.iter().flat_map(|input| {
+        input.parts.iter().filter_map(move |part| match &part.outcome {
+            Outcome::Error(e) => Some(format!("{} part {}: {}", input.name(), part.part, e)),
+            Outcome::Panic(message) => Some(format!("{} part {}: {}", input.name(), part.part, message)),
+            _ => None,
+        })
+    }).collect();
+
+    if !failures.is_empty() {
+        println!();
+        println!("Failures:");
+        for failure in failures {
+            println!("  {}", failure);
+        }
+    }
+}
+
+/// One JSON line per input and part, like `run --format json`, with the
+/// input path and a `panicked` flag for parts that panicked.
+fn print_json(day: &Day, inputs: &[BatchInput]) {
+    for input in inputs {
+        for part in &input.parts {
+            let answer = match &part.outcome {
+                Outcome::Answer(answer) => Ok(answer.clone()),
+                Outcome::Unsolved => Err(Box::new(Unsolved(part.part)).into()),
+                Outcome::Error(message) | Outcome::Panic(message) => Err(message.as_str().into()),
+            };
+
+            let json = part_json(day.number, part.part, &answer, part.elapsed)
+                .field("input", input::display_name(&input.path))
+                .field("panicked", matches!(part.outcome, Outcome::Panic(_)));
+            println!("{}", json);
+        }
+    }
+}
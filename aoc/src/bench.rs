@@ -0,0 +1,115 @@
+use std::fs;
+use std::path::PathBuf;
+
+use aoc_core::bench::{Bench, Stats};
+use aoc_core::json::Json;
+use aoc_core::{read_input, Result};
+
+use crate::cli::{RunArgs, DEFAULT_BENCH_OUTPUT, DEFAULT_SEED};
+use crate::days::{self, Day};
+use crate::report::format_duration;
+
+struct DayBench {
+    day: &'static Day,
+    bench: Result<Bench>,
+}
+
+/// Benchmarks the selected days, prints the timings and writes them to the
+/// JSON report so runs from different commits can be compared. With `--seed`
+/// or `--size` the days solve a generated input instead of their own.
+pub fn run(args: &RunArgs) -> Result<()> {
+    let selected: Vec<&'static Day> = match args.day {
+        Some(number) => days::find(number).into_iter().collect(),
+        None => days::DAYS.iter().collect(),
+    };
+
+    let parts = args.parts();
+    let config = args.config()?;
+    let mut results = Vec::new();
+    for day in selected {
+        let input = match (args.seed, args.size) {
+            (None, None) => read_input(args.input.clone().unwrap_or_else(|| day.default_input())),
+            (seed, size) => Ok(day.solver.generate(seed.unwrap_or(DEFAULT_SEED), size)),
+        };
+        let bench = input.and_then(|input| day.solver.bench(&input, &parts, &config, args.iterations));
+
+        results.push(DayBench { day, bench });
+    }
+
+    print_table(&results, args.iterations);
+
+    let mut report = to_json(&results, args.iterations);
+    if args.seed.is_some() || args.size.is_some() {
+        report = report.field("seed", args.seed.unwrap_or(DEFAULT_SEED));
+    }
+    if let Some(size) = args.size {
+        report = report.field("size", size);
+    }
+
+    let output = args.output.clone().unwrap_or_else(|| PathBuf::from(DEFAULT_BENCH_OUTPUT));
+    fs::write(&output, report.pretty() + "\n")?;
+    println!("Report written to {}", output.display());
+
+    Ok(())
+}
+
+fn print_table(results: &[DayBench], iterations: usize) {
+    println!("{} iterations per day", iterations);
+    println!("{:>3}  {:<6}  {:>10}  {:>10}  {:>10}", "Day", "Phase", "Min", "Median", "Max");
+
+    for result in results {
+        let number = result.day.number;
+
+        match &result.bench {
+            Ok(bench) => {
+                print_stats(number, "parse", &bench.parse);
+
+                for part in &bench.parts {
+                    let phase = format!("part{}", part.part);
+                    match &part.stats {
+                        Ok(stats) => print_stats(number, &phase, stats),
+                        Err(e) => println!("{:>3}  {:<6}  {}", number, phase, e),
+                    }
+                }
+            }
+            Err(e) => println!("{:>3}  {:<6}  error: {}", number, "parse", e),
+        }
+    }
+
+    println!();
+}
+
+fn print_stats(day: u8, phase: &str, stats: &Stats) {
+    println!("{:>3}  {:<6}  {:>10}  {:>10}  {:>10}",
+        day, phase, format_duration(stats.min), format_duration(stats.median), format_duration(stats.max));
+}
+
+fn to_json(results: &[DayBench], iterations: usize) -> Json {
+    let days = results.iter().map(|result| {
+        let json = Json::object()
+            .field("day", result.day.number)
+            .field("title", result.day.title);
+
+        match &result.bench {
+            Ok(bench) => bench.parts.iter().fold(json.field("parse", stats_json(&bench.parse)), |json, part| {
+                let phase = match &part.stats {
+                    Ok(stats) => stats_json(stats),
+                    Err(e) => Json::object().field("error", e.as_str()),
+                };
+                json.field(&format!("part{}", part.part), phase)
+            }),
+            Err(e) => json.field("error", e.to_string()),
+        }
+    }).collect::<Vec<Json>>();
+
+    Json::object()
+        .field("iterations", iterations)
+        .field("days", days)
+}
+
+fn stats_json(stats: &Stats) -> Json {
+    Json::object()
+        .field("min_ns", stats.min.as_nanos())
+        .field("median_ns", stats.median.as_nanos())
+        .field("max_ns", stats.max.as_nanos())
+}
@@ -0,0 +1,257 @@
+use std::fmt;
+use std::path::PathBuf;
+
+use aoc_core::log::Level;
+use aoc_core::{input, visualize, Config, Format, Part, Result};
+
+pub const USAGE: &str = "\
+Usage:
+    aoc run --day <N> [--part <1|2>] [--input <PATH>] [--record] [--answers <PATH>] [--format <FORMAT>] [LOGGING] [SETTINGS]
+    aoc run --day <N> --watch [--part <1|2>] [--input <PATH>] [--format <FORMAT>] [LOGGING] [SETTINGS]
+    aoc run --all [--part <1|2>] [--record] [--answers <PATH>] [--format <FORMAT>] [LOGGING] [SETTINGS]
+    aoc batch --day <N> --input <DIR> [--part <1|2>] [--format <FORMAT>] [LOGGING] [SETTINGS]
+    aoc bench (--day <N> [--input <PATH> | --size <N>] | --all) [--seed <N>] [--part <1|2>] [--iterations <N>] [--output <PATH>] [LOGGING] [SETTINGS]
+    aoc generate --day <N> [--seed <N>] [--size <N>] [--count <N>] [--output <PATH>]
+    aoc serve [--port <N>] [LOGGING] [SETTINGS]
+    aoc visualize --day <N> [--input <PATH>] [--fps <N>] [--frames <N>] [--output <PATH>] [LOGGING] [SETTINGS]
+
+Options:
+    -d, --day <N>         Day to solve, 1 to 25
+    -p, --part <1|2>      Solve a single part, both parts by default
+    -i, --input <PATH>    Input file, - for stdin or a directory of inputs,
+                          the day's checked-in input by default
+    -a, --all             Solve every day and print a summary table
+    -r, --record          Accept new and changed answers into the answer store
+        --answers <PATH>  Answer store, answers.jsonl in the workspace by default
+    -w, --watch           Solve again whenever the input or the settings file changes,
+                          showing how the answers differ from the previous run
+    -f, --format <FORMAT> Output format: text (default) or json, one object per part
+    -n, --iterations <N>  Benchmark iterations per day, 10 by default
+    -o, --output <PATH>   Benchmark JSON report, bench.json by default; for generate the
+                          input file, or the directory when generating several; for
+                          visualize an asciicast recording (.cast) or a text file of
+                          the frames instead of playing them
+    -s, --seed <N>        Seed of generated inputs, 0 by default; bench solves a
+                          generated input when given
+        --size <N>        Size of generated inputs (elves, lines, grid side...), about
+                          the real input's by default; bench solves a generated input
+                          when given
+    -c, --count <N>       Number of inputs to generate, one per seed from --seed on
+        --fps <N>         Visualization frames per second, 10 by default; while playing
+                          space pauses, n and p step, + and - change speed, q quits
+        --frames <N>      Most frames a visualization draws, 10000 by default
+        --port <N>        Port serve listens on at 127.0.0.1, 2022 by default, 0 for any
+                          free port; POST /day/<N>/part/<1|2> with the input as body
+
+Logging:
+    -q, --quiet           Answers only, no status lines
+    -v, --verbose         Also print how each day reached its answers
+    -vv, --trace          Also print step-by-step solver dumps
+        --log <LEVEL>     quiet, normal, verbose or trace
+
+Settings:
+        --config <PATH>   Per-day settings file, aoc.toml in the workspace by default
+        --set <KEY=VALUE> Override one setting, e.g. --set day15.fill_row_y=10
+";
+
+pub const DEFAULT_ITERATIONS: usize = 10;
+pub const DEFAULT_BENCH_OUTPUT: &str = "bench.json";
+pub const DEFAULT_SEED: u64 = 0;
+pub const DEFAULT_FPS: usize = 10;
+pub const DEFAULT_PORT: u16 = 2022;
+
+pub enum Command {
+    Run(RunArgs),
+    Batch(RunArgs),
+    Bench(RunArgs),
+    Generate(RunArgs),
+    Visualize(RunArgs),
+    Serve(RunArgs),
+    Help,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Run,
+    Batch,
+    Bench,
+    Generate,
+    Visualize,
+    Serve,
+}
+
+pub struct RunArgs {
+    pub day: Option<u8>,
+    pub part: Option<Part>,
+    pub input: Option<PathBuf>,
+    pub all: bool,
+    pub watch: bool,
+    pub record: bool,
+    pub answers: Option<PathBuf>,
+    pub format: Format,
+    pub level: Level,
+    pub config: Option<PathBuf>,
+    pub settings: Vec<String>,
+    pub iterations: usize,
+    pub output: Option<PathBuf>,
+    pub seed: Option<u64>,
+    pub size: Option<usize>,
+    pub count: usize,
+    pub fps: usize,
+    pub frames: usize,
+    pub port: u16,
+}
+
+impl Default for RunArgs {
+    fn default() -> RunArgs {
+        RunArgs {
+            day: None,
+            part: None,
+            input: None,
+            all: false,
+            watch: false,
+            record: false,
+            answers: None,
+            format: Format::Text,
+            level: Level::Normal,
+            config: None,
+            settings: Vec::new(),
+            iterations: DEFAULT_ITERATIONS,
+            output: None,
+            seed: None,
+            size: None,
+            count: 1,
+            fps: DEFAULT_FPS,
+            frames: visualize::DEFAULT_LIMIT,
+            port: DEFAULT_PORT,
+        }
+    }
+}
+
+impl RunArgs {
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+
+    /// The settings file with every `--set` override applied.
+    pub fn config(&self) -> Result<Config> {
+        Config::with_overrides(self.config.clone(), &self.settings)
+    }
+}
+
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Command> {
+    let mut args = args.into_iter();
+
+    match args.next().as_deref() {
+        Some("run") => Ok(Command::Run(parse_run(args, Mode::Run)?)),
+        Some("batch") => Ok(Command::Batch(parse_run(args, Mode::Batch)?)),
+        Some("bench") => Ok(Command::Bench(parse_run(args, Mode::Bench)?)),
+        Some("generate") => Ok(Command::Generate(parse_run(args, Mode::Generate)?)),
+        Some("visualize") => Ok(Command::Visualize(parse_run(args, Mode::Visualize)?)),
+        Some("serve") => Ok(Command::Serve(parse_run(args, Mode::Serve)?)),
+        Some("help" | "--help" | "-h") | None => Ok(Command::Help),
+        Some(other) => Err(Box::new(UsageError(format!("Unknown command '{}'", other)))),
+    }
+}
+
+fn parse_run(mut args: impl Iterator<Item = String>, mode: Mode) -> Result<RunArgs> {
+    let mut run = RunArgs::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => {
+                let day = value(&arg, args.next())?;
+                match day.parse::<u8>() {
+                    Ok(n) if (1..=25).contains(&n) => run.day = Some(n),
+                    _ => return Err(Box::new(UsageError(format!("Invalid day '{}', expected 1 to 25", day)))),
+                }
+            }
+            "--part" | "-p" => run.part = Some(value(&arg, args.next())?.parse()?),
+            "--input" | "-i" => run.input = Some(PathBuf::from(value(&arg, args.next())?)),
+            "--all" | "-a" => run.all = true,
+            "--log" => run.level = value(&arg, args.next())?.parse()?,
+            "--config" => run.config = Some(PathBuf::from(value(&arg, args.next())?)),
+            "--set" => run.settings.push(value(&arg, args.next())?),
+            "--record" | "-r" if mode == Mode::Run => run.record = true,
+            "--watch" | "-w" if mode == Mode::Run => run.watch = true,
+            "--answers" if mode == Mode::Run => run.answers = Some(PathBuf::from(value(&arg, args.next())?)),
+            "--format" | "-f" if matches!(mode, Mode::Run | Mode::Batch) => run.format = value(&arg, args.next())?.parse()?,
+            "--iterations" | "-n" if mode == Mode::Bench => {
+                let iterations = value(&arg, args.next())?;
+                match iterations.parse::<usize>() {
+                    Ok(n) if n > 0 => run.iterations = n,
+                    _ => return Err(Box::new(UsageError(format!("Invalid iteration count '{}'", iterations)))),
+                }
+            }
+            "--output" | "-o" if matches!(mode, Mode::Bench | Mode::Generate | Mode::Visualize) => run.output = Some(PathBuf::from(value(&arg, args.next())?)),
+            "--seed" | "-s" if matches!(mode, Mode::Bench | Mode::Generate) => {
+                let seed = value(&arg, args.next())?;
+                match seed.parse::<u64>() {
+                    Ok(seed) => run.seed = Some(seed),
+                    Err(_) => return Err(Box::new(UsageError(format!("Invalid seed '{}'", seed)))),
+                }
+            }
+            "--size" if matches!(mode, Mode::Bench | Mode::Generate) => run.size = Some(positive(&arg, args.next())?),
+            "--count" | "-c" if mode == Mode::Generate => run.count = positive(&arg, args.next())?,
+            "--fps" if mode == Mode::Visualize => run.fps = positive(&arg, args.next())?,
+            "--frames" if mode == Mode::Visualize => run.frames = positive(&arg, args.next())?,
+            "--port" if mode == Mode::Serve => {
+                let port = value(&arg, args.next())?;
+                match port.parse::<u16>() {
+                    Ok(port) => run.port = port,
+                    Err(_) => return Err(Box::new(UsageError(format!("Invalid port '{}'", port)))),
+                }
+            }
+            other => match Level::from_flag(other) {
+                Some(level) => run.level = level,
+                None => return Err(Box::new(UsageError(format!("Unknown option '{}'", other)))),
+            },
+        }
+    }
+
+    match (run.day, run.all) {
+        _ if mode == Mode::Serve && (run.day.is_some() || run.all || run.input.is_some()) => Err(Box::new(UsageError(String::from("serve takes the day and the input from each request")))),
+        _ if mode == Mode::Serve => Ok(run),
+        (None, false) => Err(Box::new(UsageError(String::from("Either --day or --all is required")))),
+        (Some(_), true) => Err(Box::new(UsageError(String::from("--day and --all cannot be combined")))),
+        _ if run.all && run.input.is_some() => Err(Box::new(UsageError(String::from("--input needs a single --day")))),
+        _ if run.watch && run.all => Err(Box::new(UsageError(String::from("--watch follows a single --day")))),
+        _ if run.watch && (run.record || run.answers.is_some()) => Err(Box::new(UsageError(String::from("--watch does not check answers against the answer store")))),
+        _ if run.watch && run.input.as_ref().is_some_and(|input| input::is_stdin(input) || input.is_dir()) => Err(Box::new(UsageError(String::from("--watch needs an input file")))),
+        _ if mode == Mode::Batch && run.all => Err(Box::new(UsageError(String::from("batch runs a single --day")))),
+        _ if mode == Mode::Batch && run.input.is_none() => Err(Box::new(UsageError(String::from("batch needs an --input directory")))),
+        _ if mode == Mode::Generate && run.all => Err(Box::new(UsageError(String::from("generate writes inputs for a single --day")))),
+        _ if mode == Mode::Generate && run.input.is_some() => Err(Box::new(UsageError(String::from("generate does not read an --input")))),
+        _ if mode == Mode::Generate && run.count > 1 && run.output.is_none() => Err(Box::new(UsageError(String::from("--count needs an --output directory")))),
+        _ if mode == Mode::Visualize && run.all => Err(Box::new(UsageError(String::from("visualize plays a single --day")))),
+        _ if run.size.is_some() && run.all => Err(Box::new(UsageError(String::from("--size needs a single --day")))),
+        _ if (run.size.is_some() || run.seed.is_some()) && run.input.is_some() => Err(Box::new(UsageError(String::from("--input cannot be combined with a generated input")))),
+        _ => Ok(run),
+    }
+}
+
+fn value(option: &str, value: Option<String>) -> Result<String> {
+    value.ok_or_else(|| Box::new(UsageError(format!("Missing value for {}", option))).into())
+}
+
+fn positive(option: &str, value: Option<String>) -> Result<usize> {
+    let value = self::value(option, value)?;
+    match value.parse::<usize>() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(Box::new(UsageError(format!("Invalid value '{}' for {}, expected a positive number", value, option)))),
+    }
+}
+
+#[derive(Debug)]
+pub struct UsageError(String);
+
+impl std::error::Error for UsageError {}
+
+impl fmt::Display for UsageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}\n\n{}", self.0, USAGE)
+    }
+}
@@ -0,0 +1,234 @@
+use std::path::{Path, PathBuf};
+
+use aoc_core::input::DEFAULT_INPUT;
+use aoc_core::puzzle::{Solve, Solver};
+use aoc_core::visualize::{self, Visualizer};
+
+/// A single puzzle day linked into the runner.
+pub struct Day {
+    pub number: u8,
+    pub title: &'static str,
+    /// Crate directory relative to the workspace root.
+    pub dir: &'static str,
+    /// Input file name inside the crate directory.
+    pub input: &'static str,
+    pub solver: &'static dyn Solver,
+    /// Step by step frames of the day's simulation, for the days that have one.
+    pub visualize: Option<Visualizer>,
+}
+
+impl Day {
+    /// The day's checked-in input, resolved against the workspace root so the
+    /// runner works from any directory.
+    pub fn default_input(&self) -> PathBuf {
+        let workspace_root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        workspace_root.join(self.dir).join(self.input)
+    }
+}
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+pub static DAYS: [Day; 25] = [
+    Day {
+        number: 1,
+        title: "Calorie Counting",
+        dir: "advent_1_calories",
+        input: DEFAULT_INPUT,
+        solver: &Solve::<advent_1_calories::CalorieCounting>::NEW,
+        visualize: None,
+    },
+    Day {
+        number: 2,
+        title: "Rock Paper Scissors",
+        dir: "advent_2_rock_paper_scissors",
+        input: advent_2_rock_paper_scissors::INPUT,
+        solver: &Solve::<advent_2_rock_paper_scissors::RockPaperScissors>::NEW,
+        visualize: None,
+    },
+    Day {
+        number: 3,
+        title: "Rucksack Reorganization",
+        dir: "advent_3_rucksack",
+        input: DEFAULT_INPUT,
+        solver: &Solve::<advent_3_rucksack::RucksackReorganization>::NEW,
+        visualize: None,
+    },
+    Day {
+        number: 4,
+        title: "Camp Cleanup",
+        dir: "advent_4_clean_storage",
+        input: DEFAULT_INPUT,
+        solver: &Solve::<advent_4_clean_storage::CampCleanup>::NEW,
+        visualize: None,
+    },
+    Day {
+        number: 5,
+        title: "Supply Stacks",
+        dir: "advent_5_crates",
+        input: DEFAULT_INPUT,
+        solver: &Solve::<advent_5_crates::SupplyStacks>::NEW,
+        visualize: None,
+    },
+    Day {
+        number: 6,
+        title: "Tuning Trouble",
+        dir: "advent_6_tuning_trouble",
+        input: DEFAULT_INPUT,
+        solver: &Solve::<advent_6_tuning_trouble::TuningTrouble>::NEW,
+        visualize: None,
+    },
+    Day {
+        number: 7,
+        title: "No Space Left On Device",
+        dir: "advent_7_no_space_left_on_device",
+        input: DEFAULT_INPUT,
+        solver: &Solve::<advent_7_no_space_left_on_device::NoSpaceLeftOnDevice>::NEW,
+        visualize: None,
+    },
+    Day {
+        number: 8,
+        title: "Treetop Tree House",
+        dir: "advent_8_treetop_tree_house",
+        input: DEFAULT_INPUT,
+        solver: &Solve::<advent_8::TreetopTreeHouse>::NEW,
+        visualize: None,
+    },
+    Day {
+        number: 9,
+        title: "Rope Bridge",
+        dir: "advent_9_rope_bridge",
+        input: DEFAULT_INPUT,
+        solver: &Solve::<advent_9_rope_bridge::RopeBridge>::NEW,
+        visualize: Some(visualize::frames::<advent_9_rope_bridge::RopeBridge>),
+    },
+    Day {
+        number: 10,
+        title: "Cathode-Ray Tube",
+        dir: "advent_10_cathode_ray_tube",
+        input: DEFAULT_INPUT,
+        solver: &Solve::<advent_10_cathode_ray_tube::CathodeRayTube>::NEW,
+        visualize: None,
+    },
+    Day {
+        number: 11,
+        title: "Monkey in the Middle",
+        dir: "advent_11_monkey_in_the_middle",
+        input: DEFAULT_INPUT,
+        solver: &Solve::<advent_11_monkey_in_the_middle::MonkeyInTheMiddle>::NEW,
+        visualize: None,
+    },
+    Day {
+        number: 12,
+        title: "Hill Climbing Algorithm",
+        dir: "advent_12_hill_climbing_algorithm",
+        input: DEFAULT_INPUT,
+        solver: &Solve::<advent_12_hill_climbing_algorithm::HillClimbingAlgorithm>::NEW,
+        visualize: None,
+    },
+    Day {
+        number: 13,
+        title: "Distress Signal",
+        dir: "advent_13_distress_signal",
+        input: DEFAULT_INPUT,
+        solver: &Solve::<advent_13_distress_signal::DistressSignal>::NEW,
+        visualize: None,
+    },
+    Day {
+        number: 14,
+        title: "Regolith Reservoir",
+        dir: "advent_14_regolith_reservoir",
+        input: DEFAULT_INPUT,
+        solver: &Solve::<advent_14_regolith_reservoir::RegolithReservoir>::NEW,
+        visualize: Some(visualize::frames::<advent_14_regolith_reservoir::RegolithReservoir>),
+    },
+    Day {
+        number: 15,
+        title: "Beacon Exclusion Zone",
+        dir: "advent_15_beacon_exclusion_zone",
+        input: DEFAULT_INPUT,
+        solver: &Solve::<advent_15_beacon_exclusion_zone::BeaconExclusionZone>::NEW,
+        visualize: None,
+    },
+    Day {
+        number: 16,
+        title: "Proboscidea Volcanium",
+        dir: "advent_16_proboscidea_volcanium",
+        input: DEFAULT_INPUT,
+        solver: &Solve::<advent_16_proboscidea_volcanium::ProboscideaVolcanium>::NEW,
+        visualize: None,
+    },
+    Day {
+        number: 17,
+        title: "Pyroclastic Flow",
+        dir: "advent_17_pyroclastic_flow",
+        input: DEFAULT_INPUT,
+        solver: &Solve::<advent_17_pyroclastic_flow::PyroclasticFlow>::NEW,
+        visualize: Some(visualize::frames::<advent_17_pyroclastic_flow::PyroclasticFlow>),
+    },
+    Day {
+        number: 18,
+        title: "Boiling Boulders",
+        dir: "advent_18_boiling_boulders",
+        input: DEFAULT_INPUT,
+        solver: &Solve::<advent_18_boiling_boulders::BoilingBoulders>::NEW,
+        visualize: None,
+    },
+    Day {
+        number: 19,
+        title: "Not Enough Minerals",
+        dir: "advent_19_not_enough_minerals",
+        input: DEFAULT_INPUT,
+        solver: &Solve::<advent_19_not_enough_minerals::NotEnoughMinerals>::NEW,
+        visualize: None,
+    },
+    Day {
+        number: 20,
+        title: "Grove Positioning System",
+        dir: "advent_20_grove_positioning_system",
+        input: DEFAULT_INPUT,
+        solver: &Solve::<advent_20_grove_positioning_system::GrovePositioningSystem>::NEW,
+        visualize: None,
+    },
+    Day {
+        number: 21,
+        title: "Monkey Math",
+        dir: "advent_21_monkey_match",
+        input: DEFAULT_INPUT,
+        solver: &Solve::<advent_21_monkey_match::MonkeyMath>::NEW,
+        visualize: None,
+    },
+    Day {
+        number: 22,
+        title: "Monkey Map",
+        dir: "advent_22_monkey_map",
+        input: DEFAULT_INPUT,
+        solver: &Solve::<advent_22_monkey_map::MonkeyMap>::NEW,
+        visualize: None,
+    },
+    Day {
+        number: 23,
+        title: "Unstable Diffusion",
+        dir: "advent_23_unstable_diffusion",
+        input: DEFAULT_INPUT,
+        solver: &Solve::<advent_23_unstable_diffusion::UnstableDiffusion>::NEW,
+        visualize: Some(visualize::frames::<advent_23_unstable_diffusion::UnstableDiffusion>),
+    },
+    Day {
+        number: 24,
+        title: "Blizzard Basin",
+        dir: "advent_24_blizzard_basin",
+        input: DEFAULT_INPUT,
+        solver: &Solve::<advent_24_blizzard_basin::BlizzardBasin>::NEW,
+        visualize: Some(visualize::frames::<advent_24_blizzard_basin::BlizzardBasin>),
+    },
+    Day {
+        number: 25,
+        title: "Full of Hot Air",
+        dir: "advent_25_full_of_hot_air",
+        input: DEFAULT_INPUT,
+        solver: &Solve::<advent_25_full_of_hot_air::FullOfHotAir>::NEW,
+        visualize: None,
+    },
+];
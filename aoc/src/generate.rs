@@ -0,0 +1,36 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_core::{info, Result};
+
+use crate::cli::{RunArgs, DEFAULT_SEED};
+use crate::days;
+
+/// Writes random inputs for one day: a single input to stdout or to the
+/// `--output` file, or `--count` inputs named after their seeds into the
+/// `--output` directory, ready for `aoc batch`.
+pub fn run(args: &RunArgs) -> Result<()> {
+    let day = args.day.and_then(days::find).expect("Day is validated by the argument parser");
+    let seed = args.seed.unwrap_or(DEFAULT_SEED);
+
+    match (&args.output, args.count) {
+        (None, _) => print!("{}", day.solver.generate(seed, args.size)),
+        (Some(path), 1) if !path.is_dir() => {
+            fs::write(path, day.solver.generate(seed, args.size))?;
+            info!("Day {} input for seed {} written to {}", day.number, seed, path.display());
+        }
+        (Some(dir), count) => {
+            fs::create_dir_all(dir)?;
+            for seed in (seed..).take(count) {
+                fs::write(input_path(dir, seed), day.solver.generate(seed, args.size))?;
+            }
+            info!("{} day {} inputs written to {}", count, day.number, dir.display());
+        }
+    }
+
+    Ok(())
+}
+
+fn input_path(dir: &Path, seed: u64) -> PathBuf {
+    dir.join(format!("input-{}.txt", seed))
+}
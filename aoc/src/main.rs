@@ -0,0 +1,191 @@
+mod batch;
+mod bench;
+mod cli;
+mod days;
+mod generate;
+mod report;
+mod serve;
+mod store;
+mod visualize;
+mod watch;
+
+use std::path::Path;
+use std::process;
+
+use aoc_core::log::Level;
+use aoc_core::input::input_files;
+use aoc_core::{info, log, parse, read_input, Config, Format, Result};
+
+use cli::{Command, RunArgs};
+use days::Day;
+use report::{PartRun, Table};
+use store::{AnswerStore, Verdict};
+
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("{}", parse::report(&e));
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<()> {
+    let command = cli::parse(std::env::args().skip(1))?;
+    if let Command::Run(args) | Command::Batch(args) | Command::Bench(args) | Command::Generate(args) | Command::Visualize(args) | Command::Serve(args) = &command {
+        log::set_level(args.level);
+    }
+
+    match command {
+        Command::Help => print!("{}", cli::USAGE),
+        Command::Run(args) if args.all => run_all(&args)?,
+        Command::Run(args) if args.watch => watch::run(&args)?,
+        Command::Run(args) => run_day(&args)?,
+        Command::Batch(args) => batch::run(&args)?,
+        Command::Bench(args) => bench::run(&args)?,
+        Command::Generate(args) => generate::run(&args)?,
+        Command::Visualize(args) => visualize::run(&args)?,
+        Command::Serve(args) => serve::run(&args)?,
+    }
+
+    Ok(())
+}
+
+fn run_day(args: &RunArgs) -> Result<()> {
+    let day = args.day.and_then(days::find).expect("Day is validated by the argument parser");
+    let path = args.input.clone().unwrap_or_else(|| day.default_input());
+    let files = input_files(&path)?;
+    let config = args.config()?;
+    let mut store = load_store(args)?;
+
+    if files.len() == 1 && files[0] == path {
+        let runs = solve_input(day, &path, None, &config, &mut store, args)?;
+        return finish_store(&mut store, &runs, args.record);
+    }
+
+    let mut runs = Vec::new();
+    let mut failed = 0;
+    for file in &files {
+        if args.format == Format::Text && log::enabled(Level::Normal) {
+            println!("==> {} <==", file.display());
+        }
+        match solve_input(day, file, Some(file), &config, &mut store, args) {
+            Ok(file_runs) => runs.extend(file_runs),
+            Err(e) => {
+                eprintln!("{}", parse::report(&e));
+                failed += 1;
+            }
+        }
+    }
+
+    finish_store(&mut store, &runs, args.record)?;
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{} of {} inputs in {} failed", failed, files.len(), path.display()).into()),
+    }
+}
+
+/// Solves one input file and prints its answers, naming the file in JSON
+/// records when `name` is given.
+fn solve_input(day: &'static Day, path: &Path, name: Option<&Path>, config: &Config, store: &mut AnswerStore, args: &RunArgs) -> Result<Vec<PartRun>> {
+    let input = read_input(path)?;
+    let run = day.solver.run(&input, &args.parts(), config).map_err(|e| parse::in_file(e, path))?;
+    // Diagnostics go to stderr, so stdout carries only the answers
+    info!("Day {} input parsed ({})", day.number, report::format_duration(run.parse_elapsed));
+
+    let mut runs: Vec<PartRun> = run.parts.into_iter()
+        .map(|part_run| PartRun::new(day, part_run).with_input(name))
+        .collect();
+    check_answers(store, &mut runs, &input, config, args.record);
+
+    match args.format {
+        Format::Text => runs.iter().for_each(report::print_run),
+        Format::Json => runs.iter().for_each(report::print_json),
+    }
+
+    Ok(runs)
+}
+
+/// Solves every day on its checked-in input, printing each day's answers as
+/// soon as it is done.
+fn run_all(args: &RunArgs) -> Result<()> {
+    let config = args.config()?;
+    let mut store = load_store(args)?;
+    let mut runs = Vec::new();
+    let mut table = (args.format == Format::Text).then(|| Table::start(&days::DAYS));
+
+    for day in &days::DAYS {
+        let parts = args.parts();
+        let mut day_runs: Vec<PartRun> = match read_input(day.default_input()) {
+            Err(e) => parts.iter().map(|&part| PartRun::failed(day, part, &e)).collect(),
+            Ok(input) => match day.solver.run(&input, &parts, &config) {
+                Ok(run) => {
+                    let mut day_runs: Vec<PartRun> = run.parts.into_iter().map(|part_run| PartRun::new(day, part_run)).collect();
+                    check_answers(&mut store, &mut day_runs, &input, &config, args.record);
+                    day_runs
+                }
+                Err(e) => parts.iter().map(|&part| PartRun::failed(day, part, &e)).collect(),
+            },
+        };
+
+        for run in &day_runs {
+            match &mut table {
+                Some(table) => table.print(run),
+                None => report::print_json(run),
+            }
+        }
+        runs.append(&mut day_runs);
+    }
+
+    if let Some(table) = &table {
+        table.finish();
+    }
+    finish_store(&mut store, &runs, args.record)
+}
+
+fn load_store(args: &RunArgs) -> Result<AnswerStore> {
+    AnswerStore::load(args.answers.clone().unwrap_or_else(AnswerStore::default_path))
+}
+
+/// Compares every answer of one input against the store and, with
+/// `--record`, accepts the new and changed ones. Days run with custom
+/// settings are skipped, their answers are for a different puzzle.
+fn check_answers(store: &mut AnswerStore, runs: &mut [PartRun], input: &str, config: &Config, record: bool) {
+    let hash = store::input_hash(input);
+
+    for run in runs {
+        let Ok(answer) = &run.outcome else { continue };
+        if config.has_settings(run.day.number) {
+            continue;
+        }
+        let verdict = store.check(run.day.number, run.part, &hash, answer);
+
+        if record && verdict != Verdict::Match {
+            store.record(run.day.number, run.part, &hash, answer);
+        }
+        run.verdict = Some(verdict);
+    }
+}
+
+/// Saves recorded answers, or fails when answers changed without `--record`
+/// so a reworked solver cannot silently regress.
+fn finish_store(store: &mut AnswerStore, runs: &[PartRun], record: bool) -> Result<()> {
+    let count = |wanted: fn(&Verdict) -> bool| runs.iter().filter(|r| r.verdict.as_ref().is_some_and(wanted)).count();
+    let new = count(|v| *v == Verdict::New);
+    let changed = count(|v| matches!(v, Verdict::Changed { .. }));
+
+    if record {
+        if new + changed > 0 {
+            store.save()?;
+            info!("Recorded {} answers in {}", new + changed, store.path().display());
+        }
+        return Ok(());
+    }
+
+    if new > 0 {
+        info!("{} new answers, rerun with --record to keep them", new);
+    }
+    if changed > 0 {
+        return Err(format!("{} answers changed since they were recorded, rerun with --record to accept them", changed).into());
+    }
+
+    Ok(())
+}
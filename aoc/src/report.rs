@@ -0,0 +1,131 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use aoc_core::{format, input, puzzle, Answer, Error, Part, Result, Unsolved};
+
+use crate::days::Day;
+use crate::store::Verdict;
+
+/// Outcome of solving one part of one day.
+pub struct PartRun {
+    pub day: &'static Day,
+    pub part: Part,
+    pub outcome: Result<Answer>,
+    pub elapsed: Duration,
+    /// Comparison against the answer store, for parts that produced an answer.
+    pub verdict: Option<Verdict>,
+    /// Input file, when several inputs are solved in one run.
+    pub input: Option<PathBuf>,
+}
+
+impl PartRun {
+    pub fn new(day: &'static Day, run: puzzle::PartRun) -> PartRun {
+        PartRun { day, part: run.part, outcome: run.answer, elapsed: run.elapsed, verdict: None, input: None }
+    }
+
+    pub fn with_input(mut self, input: Option<&Path>) -> PartRun {
+        self.input = input.map(Path::to_path_buf);
+        self
+    }
+
+    /// A part that could not run because its input failed to load or parse.
+    pub fn failed(day: &'static Day, part: Part, error: &Error) -> PartRun {
+        PartRun { day, part, outcome: Err(error.to_string().into()), elapsed: Duration::ZERO, verdict: None, input: None }
+    }
+
+    pub fn describe(&self) -> String {
+        match &self.outcome {
+            Ok(answer) => answer.to_string(),
+            Err(e) if e.is::<Unsolved>() => String::from("unsolved"),
+            Err(e) => format!("error: {}", e),
+        }
+    }
+}
+
+pub fn print_run(run: &PartRun) {
+    let answer = run.describe();
+    let mut header = format!("Day {} part {} ({})", run.day.number, run.part, format_duration(run.elapsed));
+    if let Some(verdict) = &run.verdict {
+        header += &format!(" [{}]", verdict);
+    }
+
+    if answer.contains('\n') {
+        println!("{}:\n{}", header, answer);
+    } else {
+        println!("{}: {}", header, answer);
+    }
+}
+
+/// Prints the part as a single JSON line, with the answer store verdict in
+/// `check` and the input file in `input` when there are.
+pub fn print_json(run: &PartRun) {
+    let mut json = format::part_json(run.day.number, run.part, &run.outcome, run.elapsed);
+    if let Some(verdict) = &run.verdict {
+        json = json.field("check", verdict.to_string());
+    }
+    if let Some(input) = &run.input {
+        json = json.field("input", input::display_name(input));
+    }
+
+    println!("{}", json);
+}
+
+/// Width of the answer column. Answers are not known before their day is
+/// solved, so longer ones push the columns after them out of line.
+const ANSWER_WIDTH: usize = 20;
+
+/// Table of answers printed one row per part as each day is solved, so the
+/// rows already out stay there when a later day never finishes.
+/// Multi-line answers continue on the following rows, aligned under the
+/// answer column.
+pub struct Table {
+    title_width: usize,
+    parts: usize,
+    solved: usize,
+    total: Duration,
+}
+
+impl Table {
+    /// Prints the header, sized for the titles of `days`.
+    pub fn start(days: &[Day]) -> Table {
+        let title_width = days.iter().map(|day| day.title.len()).max().unwrap_or(0);
+        println!("{:>3}  {:<title_width$}  {:>4}  {:<ANSWER_WIDTH$}  {:>10}  Check", "Day", "Title", "Part", "Answer", "Time");
+
+        Table { title_width, parts: 0, solved: 0, total: Duration::ZERO }
+    }
+
+    pub fn print(&mut self, run: &PartRun) {
+        let title_width = self.title_width;
+        let answer = run.describe();
+        let mut lines = answer.lines();
+        let first = lines.next().unwrap_or("");
+        let verdict = run.verdict.as_ref().map(Verdict::to_string).unwrap_or_default();
+        println!("{:>3}  {:<title_width$}  {:>4}  {:<ANSWER_WIDTH$}  {:>10}  {}",
+            run.day.number, run.day.title, run.part.number(), first, format_duration(run.elapsed), verdict);
+
+        for line in lines {
+            println!("{:>3}  {:<title_width$}  {:>4}  {}", "", "", "", line);
+        }
+
+        self.parts += 1;
+        self.solved += run.outcome.is_ok() as usize;
+        self.total += run.elapsed;
+    }
+
+    pub fn finish(&self) {
+        println!();
+        println!("Solved {} of {} parts in {}", self.solved, self.parts, format_duration(self.total));
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1_000_000.0;
+
+    if micros < 1_000.0 {
+        format!("{:.0} µs", micros)
+    } else if micros < 1_000_000.0 {
+        format!("{:.2} ms", micros / 1_000.0)
+    } else {
+        format!("{:.2} s", micros / 1_000_000.0)
+    }
+}
@@ -0,0 +1,238 @@
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::time::{Duration, Instant};
+
+use aoc_core::json::Json;
+use aoc_core::puzzle::catch_panic;
+use aoc_core::{format, info, verbose, Config, Error, ParseError, Part, Result, Unsolved};
+
+use crate::cli::RunArgs;
+use crate::days::{self, Day};
+
+/// Largest input accepted, well above any real puzzle input.
+const MAX_BODY: usize = 16 << 20;
+/// Longest request line or header line accepted, in bytes.
+const MAX_LINE: usize = 8 << 10;
+/// Most headers accepted in one request.
+const MAX_HEADERS: usize = 100;
+/// How long a client may take to send its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Serves the solvers over HTTP on localhost until killed, one request at a
+/// time. `POST /day/{n}/part/{p}` with the puzzle input as the body answers
+/// with the part's JSON record (see [`format::part_json`]) plus the parse
+/// time in `parse_elapsed`:
+///
+/// - 200 with the answer,
+/// - 400 when the input does not parse, with the parse error's `line`,
+///   `column`, `found` and `expected`,
+/// - 422 when the solver fails on a parsed input, 501 for an unsolved part
+///   and 500 when it panics or the server's settings for the day are bad,
+/// - 404, 405, 411, 413, 414 or 431 for requests that are not a solve
+///   request or are too large to read.
+pub fn run(args: &RunArgs) -> Result<()> {
+    let config = args.config()?;
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, args.port))?;
+    info!("Listening on http://{}", listener.local_addr()?);
+
+    for stream in listener.incoming() {
+        let result = stream.map_err(Error::from).and_then(|stream| serve(stream, &config));
+        if let Err(e) = result {
+            verbose!("Connection dropped: {}", e);
+        }
+    }
+
+    Ok(())
+}
+
+fn serve(mut stream: TcpStream, config: &Config) -> Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let start = Instant::now();
+
+    let (line, response) = match read_request(&mut stream) {
+        Ok(request) => (format!("{} {}", request.method, request.path), respond(&request, config)),
+        Err(response) => (String::from("(malformed request)"), response),
+    };
+    verbose!("{} -> {} ({:.1} ms)", line, response, start.elapsed().as_secs_f64() * 1000.0);
+
+    response.write_to(&mut stream)?;
+    Ok(())
+}
+
+struct Request {
+    method: String,
+    path: String,
+    body: Vec<u8>,
+}
+
+/// Reads the request line, the headers and a body of `Content-Length`
+/// bytes. A request that cannot be read becomes the error response to send.
+fn read_request(stream: &mut TcpStream) -> std::result::Result<Request, Response> {
+    let mut reader = BufReader::new(stream);
+
+    let request_line = read_line(&mut reader, 414)?;
+    let (method, path) = match request_line.split_whitespace().collect::<Vec<_>>()[..] {
+        [method, path, version] if version.starts_with("HTTP/1.") => (method.to_string(), path.to_string()),
+        _ => return Err(Response::error(400, format!("Malformed request line '{}'", request_line.trim_end()))),
+    };
+
+    let mut content_length = None;
+    for count in 0.. {
+        let header = read_line(&mut reader, 431)?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if count == MAX_HEADERS {
+            return Err(Response::error(431, format!("Requests are limited to {} headers", MAX_HEADERS)));
+        }
+
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                let length = value.trim().parse::<usize>()
+                    .map_err(|_| Response::error(400, format!("Invalid Content-Length '{}'", value.trim())))?;
+                content_length = Some(length);
+            }
+        }
+    }
+
+    let body = match (method.as_str(), content_length) {
+        (_, Some(length)) if length > MAX_BODY => return Err(Response::error(413, format!("Inputs are limited to {} bytes", MAX_BODY))),
+        (_, Some(length)) => {
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).map_err(malformed)?;
+            body
+        }
+        ("POST", None) => return Err(Response::error(411, "The input needs a Content-Length")),
+        (_, None) => Vec::new(),
+    };
+
+    Ok(Request { method, path, body })
+}
+
+/// Reads one line of the request head, failing with `status` when it is
+/// longer than [`MAX_LINE`] so a client cannot make the server buffer
+/// without end.
+fn read_line(reader: &mut impl BufRead, status: u16) -> std::result::Result<String, Response> {
+    let mut line = String::new();
+    reader.take(MAX_LINE as u64 + 1).read_line(&mut line).map_err(malformed)?;
+    if line.len() > MAX_LINE {
+        return Err(Response::error(status, format!("Request lines are limited to {} bytes", MAX_LINE)));
+    }
+
+    Ok(line)
+}
+
+fn malformed(e: io::Error) -> Response {
+    Response::error(400, format!("Malformed request: {}", e))
+}
+
+fn respond(request: &Request, config: &Config) -> Response {
+    let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+    let ["day", day, "part", part] = segments[..] else {
+        return Response::error(404, format!("No route for {}, solve with POST /day/{{n}}/part/{{p}}", request.path));
+    };
+    let Some(day) = day.parse::<u8>().ok().and_then(days::find) else {
+        return Response::error(404, format!("No day '{}', expected 1 to 25", day));
+    };
+    let Ok(part) = part.parse::<Part>() else {
+        return Response::error(404, format!("No part '{}', expected 1 or 2", part));
+    };
+    if request.method != "POST" {
+        return Response { allow: Some("POST"), ..Response::error(405, "Solve a part with POST, the input as the body") };
+    }
+    let Ok(input) = std::str::from_utf8(&request.body) else {
+        return Response::error(400, "The input is not valid UTF-8");
+    };
+
+    solve(day, part, input, config)
+}
+
+fn solve(day: &Day, part: Part, input: &str, config: &Config) -> Response {
+    let run = match catch_panic(|| day.solver.run(input, &[part], config)) {
+        Ok(Ok(run)) => run,
+        Ok(Err(e)) if e.is::<ParseError>() => return Response::new(400, error_json(day, part, &e)),
+        // Parsing went through, so the server's own settings were rejected
+        Ok(Err(e)) => return Response::new(500, error_json(day, part, &e)),
+        Err(message) => return Response::new(500, error_json(day, part, &message.into()).field("panicked", true)),
+    };
+
+    let part_run = run.parts.into_iter().next().expect("One part was run");
+    let status = match &part_run.answer {
+        Ok(_) => 200,
+        Err(e) if e.is::<Unsolved>() => 501,
+        Err(_) => 422,
+    };
+    let body = format::part_json(day.number, part, &part_run.answer, part_run.elapsed)
+        .field("parse_elapsed", run.parse_elapsed.as_secs_f64());
+
+    Response::new(status, body)
+}
+
+/// Failure of a part before it got to solve, with where the input went
+/// wrong when it did not parse.
+fn error_json(day: &Day, part: Part, error: &Error) -> Json {
+    let json = Json::object()
+        .field("day", day.number)
+        .field("part", part.number())
+        .field("error", error.to_string());
+
+    match error.downcast_ref::<ParseError>() {
+        Some(e) => json
+            .field("line", e.line)
+            .field("column", e.column)
+            .field("found", e.found.as_str())
+            .field("expected", e.expected.as_str()),
+        None => json,
+    }
+}
+
+struct Response {
+    status: u16,
+    body: Json,
+    /// Methods the route accepts, for 405 responses.
+    allow: Option<&'static str>,
+}
+
+impl Response {
+    fn new(status: u16, body: Json) -> Response {
+        Response { status, body, allow: None }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Response {
+        Response::new(status, Json::object().field("error", message.into()))
+    }
+
+    fn write_to(&self, stream: &mut TcpStream) -> io::Result<()> {
+        let body = format!("{}\n", self.body);
+        let mut head = format!("HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n", self, body.len());
+        if let Some(allow) = self.allow {
+            head += &format!("Allow: {}\r\n", allow);
+        }
+
+        stream.write_all(format!("{}\r\n{}", head, body).as_bytes())?;
+        stream.flush()
+    }
+}
+
+/// Status line text: code and reason phrase.
+impl fmt::Display for Response {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            411 => "Length Required",
+            413 => "Content Too Large",
+            414 => "URI Too Long",
+            422 => "Unprocessable Content",
+            431 => "Request Header Fields Too Large",
+            500 => "Internal Server Error",
+            501 => "Not Implemented",
+            _ => "Unknown",
+        };
+        write!(f, "{} {}", self.status, reason)
+    }
+}
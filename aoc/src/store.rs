@@ -0,0 +1,162 @@
+use std::fmt;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use aoc_core::json::Json;
+use aoc_core::{Answer, Part, Result};
+
+/// Answers store file name in the workspace root.
+pub const DEFAULT_STORE: &str = "answers.jsonl";
+
+/// Known-good answers, one JSON object per line:
+/// `{"day":1,"part":1,"input":"<hash>","answer":"71124"}`.
+///
+/// Entries are keyed by day, part and a hash of the input, so answers for the
+/// checked-in inputs and for any other input file live side by side.
+pub struct AnswerStore {
+    path: PathBuf,
+    entries: Vec<Entry>,
+}
+
+struct Entry {
+    day: u8,
+    part: Part,
+    input: String,
+    answer: String,
+}
+
+/// How a fresh answer compares to the stored one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Match,
+    Changed { previous: String },
+    New,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Match => write!(f, "MATCH"),
+            Verdict::Changed { previous } if previous.contains('\n') => write!(f, "CHANGED"),
+            Verdict::Changed { previous } => write!(f, "CHANGED (was {})", previous),
+            Verdict::New => write!(f, "NEW"),
+        }
+    }
+}
+
+impl AnswerStore {
+    pub fn default_path() -> PathBuf {
+        let workspace_root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        workspace_root.join(DEFAULT_STORE)
+    }
+
+    /// Loads the store, starting an empty one when the file does not exist.
+    pub fn load(path: impl Into<PathBuf>) -> Result<AnswerStore> {
+        let path = path.into();
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut entries = Vec::new();
+        for (i, line) in text.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            let entry = Json::parse(line).ok()
+                .as_ref()
+                .and_then(Entry::from_json)
+                .ok_or_else(|| StoreError { path: path.clone(), line: i + 1 })?;
+            entries.push(entry);
+        }
+
+        Ok(AnswerStore { path, entries })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn check(&self, day: u8, part: Part, input: &str, answer: &Answer) -> Verdict {
+        match self.find(day, part, input) {
+            Some(entry) if entry.answer == answer.to_string() => Verdict::Match,
+            Some(entry) => Verdict::Changed { previous: entry.answer.clone() },
+            None => Verdict::New,
+        }
+    }
+
+    /// Stores `answer` as the known-good one, replacing any previous answer.
+    pub fn record(&mut self, day: u8, part: Part, input: &str, answer: &Answer) {
+        let answer = answer.to_string();
+        let position = self.entries.iter().position(|e| e.day == day && e.part == part && e.input == input);
+
+        match position {
+            Some(i) => self.entries[i].answer = answer,
+            None => self.entries.push(Entry { day, part, input: input.to_string(), answer }),
+        }
+    }
+
+    /// Writes the store back sorted by day, part and input so the file diffs
+    /// cleanly.
+    pub fn save(&mut self) -> Result<()> {
+        self.entries.sort_by(|a, b| (a.day, a.part, &a.input).cmp(&(b.day, b.part, &b.input)));
+
+        let text: String = self.entries.iter().map(|entry| entry.to_json().to_string() + "\n").collect();
+        fs::write(&self.path, text)?;
+
+        Ok(())
+    }
+
+    fn find(&self, day: u8, part: Part, input: &str) -> Option<&Entry> {
+        self.entries.iter().find(|e| e.day == day && e.part == part && e.input == input)
+    }
+}
+
+impl Entry {
+    fn from_json(json: &Json) -> Option<Entry> {
+        let part = match json.get("part")?.as_i64()? {
+            1 => Part::One,
+            2 => Part::Two,
+            _ => return None,
+        };
+
+        Some(Entry {
+            day: u8::try_from(json.get("day")?.as_i64()?).ok()?,
+            part,
+            input: json.get("input")?.as_str()?.to_string(),
+            answer: json.get("answer")?.as_str()?.to_string(),
+        })
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object()
+            .field("day", self.day)
+            .field("part", self.part.number())
+            .field("input", self.input.as_str())
+            .field("answer", self.answer.as_str())
+    }
+}
+
+/// Stable hash of an input file: 64-bit FNV-1a, as 16 hex digits. The std
+/// hasher is not guaranteed to stay the same between Rust releases, which
+/// would orphan every stored answer.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+
+    format!("{:016x}", hash)
+}
+
+#[derive(Debug)]
+pub struct StoreError {
+    path: PathBuf,
+    line: usize,
+}
+
+impl std::error::Error for StoreError {}
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: expected an object with day, part, input and answer", self.path.display(), self.line)
+    }
+}
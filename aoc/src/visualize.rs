@@ -0,0 +1,187 @@
+use std::fs;
+use std::io::{self, IsTerminal, Read, Write};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use aoc_core::visualize::{self as frames, Frame, CLEAR_SCREEN};
+use aoc_core::{info, parse, read_input, Result};
+
+use crate::cli::RunArgs;
+use crate::days;
+
+const MAX_FPS: usize = 1000;
+
+/// Draws the frames of one day's simulation and plays them in the terminal,
+/// or writes them to the `--output` file: an asciicast recording for a
+/// `.cast` file, plain text otherwise. Without a terminal to play in, the
+/// frames are printed as text.
+pub fn run(args: &RunArgs) -> Result<()> {
+    let day = args.day.and_then(days::find).expect("Day is validated by the argument parser");
+    let Some(visualize) = day.visualize else {
+        let days = days::DAYS.iter().filter(|day| day.visualize.is_some()).map(|day| day.number.to_string()).collect::<Vec<_>>();
+        return Err(format!("Day {} has no simulation to visualize, days {} have one", day.number, days.join(", ")).into());
+    };
+
+    let path = args.input.clone().unwrap_or_else(|| day.default_input());
+    let input = read_input(&path)?;
+    let frames = visualize(&input, &args.config()?, args.frames).map_err(|e| parse::in_file(e, &path))?;
+    info!("Day {} simulation drawn in {} frames", day.number, frames.len());
+
+    match &args.output {
+        Some(output) if output.extension().is_some_and(|extension| extension == "cast") => {
+            let title = format!("Day {}: {}", day.number, day.title);
+            fs::write(output, frames::to_asciicast(&frames, args.fps, &title))?;
+            info!("Recording written to {}", output.display());
+        }
+        Some(output) => {
+            fs::write(output, frames::to_text(&frames))?;
+            info!("Frames written to {}", output.display());
+        }
+        None if io::stdout().is_terminal() => play(&frames, args.fps)?,
+        None => print!("{}", frames::to_text(&frames)),
+    }
+
+    Ok(())
+}
+
+/// Shows the frames one after the other. With a keyboard attached, space
+/// pauses, `n` and `p` step forwards and backwards, `+` and `-` double or
+/// halve the speed, `r` restarts and `q` quits; playback waits on the last
+/// frame. Without one the frames just play through.
+fn play(frames: &[Frame], fps: usize) -> Result<()> {
+    let terminal = RawTerminal::enable();
+    let keys = terminal.as_ref().map(|_| listen());
+    let mut player = Player { frames, index: 0, fps, paused: false };
+
+    loop {
+        player.draw(keys.is_some())?;
+
+        let key = match &keys {
+            Some(keys) if player.paused || player.at_end() => match keys.recv() {
+                Ok(key) => Some(key),
+                Err(_) => break,
+            },
+            Some(keys) => match keys.recv_timeout(player.delay()) {
+                Ok(key) => Some(key),
+                Err(RecvTimeoutError::Timeout) => None,
+                Err(RecvTimeoutError::Disconnected) => break,
+            },
+            None if player.at_end() => break,
+            None => {
+                thread::sleep(player.delay());
+                None
+            }
+        };
+
+        match key {
+            // Ctrl-C arrives as a key too, the terminal is not sending signals
+            Some(b'q' | 0x03) => break,
+            Some(key) => player.press(key),
+            None => player.index += 1,
+        }
+    }
+
+    Ok(())
+}
+
+struct Player<'a> {
+    frames: &'a [Frame],
+    index: usize,
+    fps: usize,
+    paused: bool,
+}
+
+impl Player<'_> {
+    fn at_end(&self) -> bool {
+        self.index + 1 >= self.frames.len()
+    }
+
+    fn delay(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.fps as f64)
+    }
+
+    fn press(&mut self, key: u8) {
+        match key {
+            b' ' => self.paused = !self.paused,
+            b'n' => {
+                self.paused = true;
+                if !self.at_end() {
+                    self.index += 1;
+                }
+            }
+            b'p' => {
+                self.paused = true;
+                self.index = self.index.saturating_sub(1);
+            }
+            b'+' | b'=' => self.fps = (self.fps * 2).min(MAX_FPS),
+            b'-' => self.fps = (self.fps / 2).max(1),
+            b'r' => self.index = 0,
+            _ => {}
+        }
+    }
+
+    fn draw(&self, interactive: bool) -> Result<()> {
+        let Some(frame) = self.frames.get(self.index) else {
+            return Ok(());
+        };
+
+        let mut stdout = io::stdout().lock();
+        write!(stdout, "{}{}\n{}\n", CLEAR_SCREEN, frame.caption, frame.picture)?;
+        write!(stdout, "Frame {}/{} at {} fps", self.index + 1, self.frames.len(), self.fps)?;
+        match (interactive, self.paused, self.at_end()) {
+            (false, ..) => writeln!(stdout)?,
+            (true, true, _) => writeln!(stdout, ", paused   [space] play  [n/p] step  [+/-] speed  [r] restart  [q] quit")?,
+            (true, false, true) => writeln!(stdout, ", done   [p] step back  [r] restart  [q] quit")?,
+            (true, false, false) => writeln!(stdout, "   [space] pause  [n/p] step  [+/-] speed  [r] restart  [q] quit")?,
+        }
+        stdout.flush()?;
+
+        Ok(())
+    }
+}
+
+/// Key presses read from stdin on a background thread.
+fn listen() -> Receiver<u8> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for byte in io::stdin().lock().bytes() {
+            let Ok(byte) = byte else { break };
+            if sender.send(byte).is_err() {
+                break;
+            }
+        }
+    });
+    receiver
+}
+
+/// The terminal switched to passing on every key press unechoed, put back
+/// the way it was when dropped. Uses `stty` rather than a terminal crate.
+struct RawTerminal {
+    saved: String,
+}
+
+impl RawTerminal {
+    /// `None` when stdin is not a terminal or `stty` is not available.
+    fn enable() -> Option<RawTerminal> {
+        if !io::stdin().is_terminal() {
+            return None;
+        }
+
+        let saved = stty(&["-g"])?;
+        stty(&["-icanon", "-echo", "-isig", "min", "1"])?;
+        Some(RawTerminal { saved: saved.trim().to_string() })
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        stty(&[&self.saved]);
+    }
+}
+
+fn stty(args: &[&str]) -> Option<String> {
+    let output = Command::new("stty").args(args).stdin(Stdio::inherit()).output().ok()?;
+    output.status.success().then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
@@ -0,0 +1,114 @@
+use std::fs;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use aoc_core::input::display_name;
+use aoc_core::log::{self, Level};
+use aoc_core::{format, info, parse, read_input, Config, Format, Part, Result};
+
+use crate::cli::RunArgs;
+use crate::days::{self, Day};
+use crate::report::{self, PartRun};
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Solves one day's input, then solves it again whenever the input or the
+/// settings file changes, until interrupted. Every answer after the first
+/// run is compared against the previous one. Failing runs are reported and
+/// the watch goes on, waiting for the next edit.
+pub fn run(args: &RunArgs) -> Result<()> {
+    let day = args.day.and_then(days::find).expect("Day is validated by the argument parser");
+    let input = args.input.clone().unwrap_or_else(|| day.default_input());
+    let config = args.config.clone().unwrap_or_else(Config::default_path);
+
+    let mut stamps = (stamp(&input), stamp(&config));
+    let mut previous = solve(day, &input, args, &Vec::new());
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let current = (stamp(&input), stamp(&config));
+        if current == stamps {
+            continue;
+        }
+
+        let changed = if current.0 != stamps.0 { &input } else { &config };
+        let header = format!("==> {} changed <==", display_name(changed));
+        match args.format {
+            Format::Text if log::enabled(Level::Normal) => println!("{}", header),
+            Format::Text => {}
+            Format::Json => info!("{}", header),
+        }
+        stamps = current;
+        previous = solve(day, &input, args, &previous);
+    }
+}
+
+/// Answers of the run, kept to compare the next one against.
+type Answers = Vec<(Part, String)>;
+
+fn solve(day: &'static Day, path: &Path, args: &RunArgs, previous: &Answers) -> Answers {
+    let run = args.config().and_then(|config| {
+        let input = read_input(path)?;
+        day.solver.run(&input, &args.parts(), &config).map_err(|e| parse::in_file(e, path))
+    });
+    let run = match run {
+        Ok(run) => run,
+        Err(e) => {
+            eprintln!("{}", parse::report(&e));
+            return previous.clone();
+        }
+    };
+
+    let mut answers = Vec::new();
+    for part_run in run.parts {
+        let run = PartRun::new(day, part_run);
+        let answer = run.describe();
+        let was = previous.iter().find(|(part, _)| *part == run.part).map(|(_, answer)| answer.as_str());
+
+        match args.format {
+            Format::Text => print_diff(&run, &answer, was),
+            Format::Json => {
+                let json = format::part_json(day.number, run.part, &run.outcome, run.elapsed)
+                    .field("previous", was)
+                    .field("changed", was.is_some_and(|was| was != answer));
+                println!("{}", json);
+            }
+        }
+        answers.push((run.part, answer));
+    }
+
+    answers
+}
+
+/// Prints the answer, then how it differs from the previous run's: `was`
+/// for a single line, removed and added lines for a multi-line answer.
+fn print_diff(run: &PartRun, answer: &str, was: Option<&str>) {
+    let header = format!("Day {} part {} ({})", run.day.number, run.part, report::format_duration(run.elapsed));
+
+    match was {
+        None => report::print_run(run),
+        Some(was) if was == answer && !answer.contains('\n') => println!("{}: {} (unchanged)", header, answer),
+        Some(was) if was == answer => println!("{}: unchanged", header),
+        Some(was) if !was.contains('\n') && !answer.contains('\n') => println!("{}: {} (was {})", header, answer, was),
+        Some(was) => {
+            println!("{}: changed", header);
+            let (old, new): (Vec<&str>, Vec<&str>) = (was.lines().collect(), answer.lines().collect());
+            for i in 0..old.len().max(new.len()) {
+                match (old.get(i), new.get(i)) {
+                    (Some(old), Some(new)) if old == new => println!("  {}", new),
+                    (old, new) => {
+                        old.into_iter().for_each(|line| println!("- {}", line));
+                        new.into_iter().for_each(|line| println!("+ {}", line));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Modification time and size of a file, `None` while it does not exist.
+fn stamp(path: &Path) -> Option<(SystemTime, u64)> {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
@@ -0,0 +1,86 @@
+//! `aoc batch` keeps going past inputs that fail to parse or make a solver
+//! panic, and flags them in the table.
+
+use std::fs;
+use std::process::Command;
+
+use aoc_core::puzzle::{self, catch_panic};
+use aoc_core::{Config, Part, Puzzle, Result};
+
+/// Runs `day` in batch over the given files, returning whether the run
+/// succeeded and its stdout.
+fn batch(day: u8, files: &[(&str, &str)]) -> (bool, String) {
+    let dir = std::env::temp_dir().join(format!("aoc-batch-test-{}-{}", day, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    for (file, input) in files {
+        fs::write(dir.join(file), input).unwrap();
+    }
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["batch", "--day", &day.to_string(), "--input"])
+        .arg(&dir)
+        .output()
+        .unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    (output.status.success(), String::from_utf8(output.stdout).unwrap())
+}
+
+fn row(stdout: &str, name: &str) -> String {
+    stdout.lines().find(|line| line.starts_with(name)).unwrap_or_else(|| panic!("No row for {}:\n{}", name, stdout)).to_string()
+}
+
+#[test]
+fn failing_inputs_are_flagged() {
+    let (success, stdout) = batch(20, &[("example.txt", "1\n2\n-3\n3\n-2\n0\n4\n"), ("not_a_number.txt", "1\nx\n0\n")]);
+
+    assert!(!success);
+    assert!(row(&stdout, "example.txt").contains(" 3 ") && row(&stdout, "example.txt").ends_with("ok"), "{}", stdout);
+    assert!(row(&stdout, "not_a_number.txt").ends_with("ERROR"), "{}", stdout);
+}
+
+#[test]
+fn panicking_inputs_are_flagged_and_fail_the_run() {
+    // Day 5 panics on purpose when a move takes more crates than the stack
+    // holds ("Not correct amount")
+    let example = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\nmove 2 from 2 to 1\nmove 1 from 1 to 2\n";
+    let (success, stdout) = batch(5, &[("example.txt", example), ("too_many.txt", "[A]\n 1 \n\nmove 2 from 1 to 1\n")]);
+
+    assert!(!success, "{}", stdout);
+    assert!(row(&stdout, "example.txt").ends_with("ok"), "{}", stdout);
+    assert!(row(&stdout, "too_many.txt").ends_with("PANIC"), "{}", stdout);
+    assert!(stdout.contains("too_many.txt part 1: panicked at") && stdout.contains("Not correct amount"), "{}", stdout);
+}
+
+/// Panics in part 1 on purpose, as no real day should.
+struct Panicking;
+
+impl Puzzle for Panicking {
+    const DAY: u8 = 1;
+    type Parsed = ();
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(_input: &str) -> Result<()> {
+        Ok(())
+    }
+
+    fn part1(_parsed: &()) -> Result<u32> {
+        panic!("Part 1 gives up");
+    }
+
+    fn part2(_parsed: &()) -> Result<u32> {
+        Ok(2)
+    }
+}
+
+#[test]
+fn panics_are_caught_with_their_message() {
+    let Err(message) = catch_panic(|| puzzle::run::<Panicking>("", &[Part::One], &Config::default())) else {
+        panic!("Part 1 should have panicked");
+    };
+    assert!(message.starts_with("panicked at") && message.ends_with("Part 1 gives up"), "{}", message);
+
+    let run = catch_panic(|| puzzle::run::<Panicking>("", &[Part::Two], &Config::default())).unwrap().unwrap();
+    assert_eq!(run.parts[0].answer.as_ref().unwrap().to_string(), "2");
+}
@@ -0,0 +1,216 @@
+//! Day 1's library API: the elves' inventories, ranking the elves carrying
+//! the most calories, the statistics report, the balancing plan and the
+//! running tally of a followed inventory.
+
+use aoc_core::json::Json;
+use aoc_core::{puzzle, Answer, Config, ParseError, Part, Puzzle};
+
+use advent_1_calories::follow::Tally;
+use advent_1_calories::plan::{plan, Plan, EXACT_ITEM_LIMIT};
+use advent_1_calories::report::{Bucket, Report};
+use advent_1_calories::{top_n, CalorieCounting, Elf, RankedElf};
+
+const EXAMPLE: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+fn elf(index: usize, calories: u64) -> RankedElf {
+    RankedElf { index, calories }
+}
+
+#[test]
+fn top_n_ranks_the_heaviest_carriers() {
+    let calories = [6000, 4000, 11000, 24000, 10000];
+
+    assert_eq!(top_n(&calories, 3).unwrap(), [elf(3, 24000), elf(2, 11000), elf(4, 10000)]);
+    assert_eq!(top_n(&calories, 5).unwrap().last(), Some(&elf(1, 4000)));
+    assert_eq!(top_n(&calories, 0).unwrap(), []);
+}
+
+#[test]
+fn ties_keep_input_order() {
+    assert_eq!(top_n(&[5, 7, 5, 7, 5], 3).unwrap(), [elf(1, 7), elf(3, 7), elf(0, 5)]);
+}
+
+#[test]
+fn too_few_elves_is_an_error() {
+    let error = top_n(&[1000, 2000], 3).unwrap_err();
+
+    assert_eq!((error.wanted, error.found), (3, 2));
+    assert!(puzzle::solve::<CalorieCounting>("1000\n\n2000\n", Part::Two, &Config::default()).is_err());
+    assert!(puzzle::solve::<CalorieCounting>("", Part::One, &Config::default()).is_err());
+}
+
+#[test]
+fn part_2_adds_up_the_configured_count() {
+    let mut config = Config::default();
+    assert_eq!(puzzle::solve::<CalorieCounting>(EXAMPLE, Part::Two, &config).unwrap(), Answer::from(45000));
+
+    config.set("day1.top=2").unwrap();
+    assert_eq!(puzzle::solve::<CalorieCounting>(EXAMPLE, Part::Two, &config).unwrap(), Answer::from(35000));
+    assert_eq!(puzzle::solve::<CalorieCounting>(EXAMPLE, Part::One, &config).unwrap(), Answer::from(24000));
+}
+
+#[test]
+fn totals_beyond_u32_are_added_up() {
+    let input = "4000000000\n1000000000\n\n4000000000\n\n3000000000\n";
+    assert_eq!(puzzle::solve::<CalorieCounting>(input, Part::One, &Config::default()).unwrap(), Answer::from(5_000_000_000u64));
+    assert_eq!(puzzle::solve::<CalorieCounting>(input, Part::Two, &Config::default()).unwrap(), Answer::from(12_000_000_000u64));
+}
+
+#[test]
+fn inventories_keep_every_item() {
+    let inventories = CalorieCounting::parse(EXAMPLE).unwrap();
+
+    assert_eq!(inventories.elves().len(), 5);
+    assert_eq!(inventories.elves()[3], Elf { index: 3, items: vec![7000, 8000, 9000] });
+    assert_eq!(inventories.calories(), [6000, 4000, 11000, 24000, 10000]);
+
+    let error = CalorieCounting::parse("1000\n\n20OO\n").err().unwrap();
+    let error = error.downcast_ref::<ParseError>().unwrap();
+    assert_eq!((error.line, error.found.as_str()), (3, "20OO"));
+}
+
+#[test]
+fn report_summarizes_the_loads() {
+    let report = Report::new(CalorieCounting::parse(EXAMPLE).unwrap().elves()).unwrap();
+
+    assert_eq!((report.items, report.calories, report.min, report.max), (10, 55000, 4000, 24000));
+    assert_eq!((report.mean, report.median), (11000.0, 10000.0));
+    assert_eq!(report.percentiles, [(10, 4000), (25, 6000), (50, 10000), (75, 11000), (90, 24000)]);
+    assert_eq!(report.item_counts.into_iter().collect::<Vec<_>>(), [(1, 2), (2, 1), (3, 2)]);
+    assert_eq!(report.histogram.len(), 10);
+    assert_eq!(report.histogram[0], Bucket { from: 4000, to: 6000, elves: 2 });
+    assert_eq!(report.histogram[9], Bucket { from: 22009, to: 24000, elves: 1 });
+
+    let even = Report::new(&[Elf { index: 0, items: vec![1] }, Elf { index: 1, items: vec![2] }]).unwrap();
+    assert_eq!((even.median, even.histogram.len()), (1.5, 2));
+    assert!(Report::new(&[]).is_err());
+}
+
+#[test]
+fn report_exports_csv_and_json() {
+    let report = Report::new(CalorieCounting::parse(EXAMPLE).unwrap().elves()).unwrap();
+
+    assert!(report.to_csv().starts_with("elf,items,calories\n1,3,6000\n2,1,4000\n"));
+    assert_eq!(report.to_csv().lines().count(), 6);
+
+    let json = Json::parse(&report.to_json().to_string()).unwrap();
+    assert_eq!(json.get("elves").and_then(Json::as_i64), Some(5));
+    assert_eq!(json.get("percentiles").and_then(|p| p.get("p75")).and_then(Json::as_i64), Some(11000));
+    let Some(Json::Array(loads)) = json.get("loads") else { panic!("Expected the loads") };
+    assert_eq!(loads[3].get("calories").and_then(Json::as_i64), Some(24000));
+
+    let text = report.to_string();
+    assert!(text.starts_with("Elves: 5, items: 10, calories: 55000\n"));
+    assert!(text.contains(" 4000 -  6000 | ######################################## 2\n"));
+}
+
+fn elves(items: &[&[u32]]) -> Vec<Elf> {
+    items.iter().enumerate().map(|(index, items)| Elf { index, items: items.to_vec() }).collect()
+}
+
+/// Loads after handing over the plan's items, checking every moved item
+/// is one the giving elf carries.
+fn apply(elves: &[Elf], plan: &Plan) -> Vec<u64> {
+    let mut loads: Vec<u64> = elves.iter().map(Elf::calories).collect();
+    for m in &plan.moves {
+        assert_eq!(elves[m.from].items[m.item], m.calories);
+        loads[m.from] -= m.calories as u64;
+        loads[m.to] += m.calories as u64;
+    }
+    loads
+}
+
+#[test]
+fn plan_evens_out_the_example() {
+    let inventories = CalorieCounting::parse(EXAMPLE).unwrap();
+    let plan = plan(inventories.elves()).unwrap();
+
+    assert_eq!((plan.max_load(), plan.lower_bound, plan.optimal), (11000, 11000, true));
+    assert_eq!(plan.loads, [11000; 5]);
+    assert_eq!(apply(inventories.elves(), &plan), plan.loads);
+    assert!(plan.to_csv().starts_with("from,item,calories,to\n"));
+    assert_eq!(plan.to_csv().lines().count(), plan.moves.len() + 1);
+}
+
+#[test]
+fn small_inventories_are_searched_exactly() {
+    // Both heuristics stop at 17, the best split is 11+3+2, 8+8 and 6+6+4
+    let elves = elves(&[&[11, 8, 6], &[8, 6, 4, 3, 2], &[]]);
+    let plan = plan(&elves).unwrap();
+
+    assert_eq!((plan.max_load(), plan.lower_bound, plan.optimal), (16, 16, true));
+    assert_eq!(apply(&elves, &plan), plan.loads);
+}
+
+#[test]
+fn plan_moves_only_what_it_must() {
+    assert_eq!(plan(&elves(&[&[5], &[2, 3]])).unwrap().moves, []);
+
+    // The single heaviest item bounds the balance
+    let bounded = plan(&elves(&[&[10, 1], &[1]])).unwrap();
+    assert_eq!((bounded.max_load(), bounded.lower_bound, bounded.moves.len()), (10, 10, 1));
+    assert!(plan(&[]).is_err());
+}
+
+#[test]
+fn loads_beyond_u32_are_added_up() {
+    let elves = elves(&[&[u32::MAX, u32::MAX], &[]]);
+    assert_eq!(Report::new(&elves).unwrap().max, 2 * u32::MAX as u64);
+
+    let plan = plan(&elves).unwrap();
+    assert_eq!((plan.max_load(), plan.moves.len()), (u32::MAX as u64, 1));
+    assert_eq!(apply(&elves, &plan), plan.loads);
+}
+
+#[test]
+fn large_inventories_are_balanced_greedily() {
+    let items: Vec<Vec<u32>> = (0..40).map(|elf| (0..elf % 7 + 1).map(|item| (elf * 7919 + item * 104729) % 9000 + 1000).collect()).collect();
+    let elves: Vec<Elf> = items.into_iter().enumerate().map(|(index, items)| Elf { index, items }).collect();
+    let total: u64 = elves.iter().map(Elf::calories).sum();
+    assert!(elves.iter().map(|elf| elf.items.len()).sum::<usize>() > EXACT_ITEM_LIMIT);
+
+    let plan = plan(&elves).unwrap();
+    assert_eq!(apply(&elves, &plan), plan.loads);
+    assert_eq!(plan.loads.iter().sum::<u64>(), total);
+    assert!(plan.max_load() >= plan.lower_bound);
+    assert!(plan.max_load() < elves.iter().map(Elf::calories).max().unwrap());
+}
+
+#[test]
+fn tally_follows_appended_lines() {
+    let mut tally = Tally::new(2);
+    assert!(tally.push(b"1000\n2000\n30").is_empty());
+    assert_eq!(tally.leaderboard(), [elf(0, 3000)]);
+
+    // The split line is read once complete
+    tally.push(b"00\n\n4000\n");
+    assert_eq!(tally.leaderboard(), [elf(0, 6000), elf(1, 4000)]);
+
+    tally.push(b"\n5000\n6000\n\n7000\n8000\n");
+    assert_eq!(tally.leaderboard(), [elf(3, 15000), elf(2, 11000)]);
+    assert_eq!((tally.lines(), tally.items()), (11, 8));
+
+    tally.push(b"9000\n\n10000");
+    assert!(tally.finish().is_empty());
+    assert_eq!(tally.totals(), CalorieCounting::parse(EXAMPLE).unwrap().calories());
+    assert_eq!(tally.leaderboard(), &top_n(tally.totals(), 2).unwrap()[..]);
+}
+
+#[test]
+fn tally_skips_bad_lines() {
+    let mut tally = Tally::new(3);
+
+    let errors = tally.push(b"1000\nabc\n2000\n\n1O\n");
+    assert_eq!(errors.iter().map(|e| (e.line, e.found.as_str())).collect::<Vec<_>>(), [(2, "abc"), (5, "1O")]);
+    assert_eq!(tally.totals(), [3000, 0]);
+    assert_eq!(tally.leaderboard(), [elf(0, 3000)]);
+}
+
+#[test]
+fn tally_totals_beyond_u32() {
+    let mut tally = Tally::new(1);
+
+    tally.push(b"4000000000\n4000000000\n");
+    assert_eq!(tally.totals(), [8_000_000_000]);
+    assert_eq!(tally.leaderboard(), [elf(0, 8_000_000_000)]);
+}
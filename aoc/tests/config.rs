@@ -0,0 +1,56 @@
+//! Per-day settings from `aoc.toml` and `--set` overrides reach the days and
+//! typos are reported.
+
+use aoc_core::{puzzle, Answer, Config, Part};
+
+use advent_15_beacon_exclusion_zone::BeaconExclusionZone;
+
+const DAY_15_EXAMPLE: &str = "\
+Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3
+";
+
+#[test]
+fn day_15_example_row_from_config_file() {
+    let config = Config::parse("# example sizes\n[day15]\nfill_row_y = 10\nmax_coordinate = 20\n").unwrap();
+
+    let answer = puzzle::solve::<BeaconExclusionZone>(DAY_15_EXAMPLE, Part::One, &config).unwrap();
+    assert_eq!(answer, Answer::from(26));
+}
+
+#[test]
+fn set_overrides_config_file() {
+    let mut config = Config::parse("[day15]\nfill_row_y = 2_000_000\n").unwrap();
+    config.set("day15.fill_row_y=10").unwrap();
+
+    let answer = puzzle::solve::<BeaconExclusionZone>(DAY_15_EXAMPLE, Part::One, &config).unwrap();
+    assert_eq!(answer, Answer::from(26));
+}
+
+#[test]
+fn unknown_setting_is_reported() {
+    let mut config = Config::default();
+    config.set("day15.fill_row=10").unwrap();
+
+    let error = puzzle::solve::<BeaconExclusionZone>(DAY_15_EXAMPLE, Part::One, &config).unwrap_err();
+    assert_eq!(error.to_string(), "Unknown setting day15.fill_row, known settings: fill_row_y, max_coordinate");
+}
+
+#[test]
+fn setting_outside_a_day_table_is_a_parse_error() {
+    let error = Config::parse("rounds = 10\n").unwrap_err();
+
+    assert_eq!((error.line, error.column, error.found.as_str()), (1, 1, "rounds = 10"));
+}
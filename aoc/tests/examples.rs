@@ -0,0 +1,54 @@
+//! Regression tests running every day on the worked example quoted in the
+//! exercise comment at the bottom of its source, checked against the answer
+//! the exercise text gives for it.
+
+use aoc_core::{example, puzzle, Answer, Config, Part, Puzzle};
+
+fn check<P: Puzzle>(source: &str, part: Part, expected: impl Into<Answer>) {
+    let input = example::from_exercise(source).expect("Exercise comment should contain an example input");
+    let answer = puzzle::solve::<P>(&input, part, &Config::default()).unwrap_or_else(|e| panic!("Part {} failed on the example: {}", part, e));
+
+    assert_eq!(answer, expected.into(), "example input:\n{}", input);
+}
+
+#[test]
+fn day_2_rock_paper_scissors() {
+    check::<advent_2_rock_paper_scissors::RockPaperScissors>(include_str!("../../advent_2_rock_paper_scissors/src/lib.rs"), Part::One, 15);
+    check::<advent_2_rock_paper_scissors::RockPaperScissors>(include_str!("../../advent_2_rock_paper_scissors/src/lib.rs"), Part::Two, 12);
+}
+
+#[test]
+fn day_3_rucksack() {
+    check::<advent_3_rucksack::RucksackReorganization>(include_str!("../../advent_3_rucksack/src/lib.rs"), Part::One, 157);
+}
+
+#[test]
+fn day_5_crates() {
+    check::<advent_5_crates::SupplyStacks>(include_str!("../../advent_5_crates/src/lib.rs"), Part::One, "CMZ");
+}
+
+#[test]
+fn day_8_treetop_tree_house() {
+    check::<advent_8::TreetopTreeHouse>(include_str!("../../advent_8_treetop_tree_house/src/lib.rs"), Part::One, 21);
+}
+
+/// Day 17 has no exercise comment, so its example jet pattern is quoted here.
+const DAY_17_EXAMPLE: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>\n";
+
+#[test]
+fn day_17_pyroclastic_flow() {
+    let answer = puzzle::solve::<advent_17_pyroclastic_flow::PyroclasticFlow>(DAY_17_EXAMPLE, Part::One, &Config::default()).unwrap();
+
+    assert_eq!(answer, Answer::from(3068));
+}
+
+/// Day 24 has no exercise comment either; this is its larger example valley.
+const DAY_24_EXAMPLE: &str = "#.######\n#>>.<^<#\n#.<..<<#\n#>v.><>#\n#<^v^^>#\n######.#\n";
+
+#[test]
+fn day_24_blizzard_basin() {
+    let config = Config::default();
+
+    assert_eq!(puzzle::solve::<advent_24_blizzard_basin::BlizzardBasin>(DAY_24_EXAMPLE, Part::One, &config).unwrap(), Answer::from(18));
+    assert_eq!(puzzle::solve::<advent_24_blizzard_basin::BlizzardBasin>(DAY_24_EXAMPLE, Part::Two, &config).unwrap(), Answer::from(54));
+}
@@ -0,0 +1,170 @@
+//! Property tests for the input generators: for a few seeds every day's
+//! generated input must be reproducible, parse, and solve without errors or
+//! panics. Sizes are kept small so the slower days stay quick in debug builds.
+
+use std::fs;
+use std::process::Command;
+
+use aoc_core::generate::{self, Generate};
+use aoc_core::{puzzle, Config, Part, Unsolved};
+
+const SEEDS: [u64; 4] = [0, 1, 2, 42];
+const BOTH: &[Part] = &Part::ALL;
+
+fn check<P: Generate>(size: usize, parts: &[Part]) {
+    for seed in SEEDS {
+        let input = generate::generate::<P>(seed, Some(size));
+        assert_eq!(input, generate::generate::<P>(seed, Some(size)), "seed {} gave two different inputs", seed);
+
+        for &part in parts {
+            match puzzle::solve::<P>(&input, part, &Config::default()) {
+                Ok(_) => (),
+                Err(e) if e.is::<Unsolved>() => (),
+                Err(e) => panic!("Part {} failed on seed {}: {}\ninput:\n{}", part, seed, e, input),
+            }
+        }
+    }
+}
+
+#[test]
+fn day_1_calories() {
+    check::<advent_1_calories::CalorieCounting>(20, BOTH);
+}
+
+#[test]
+fn day_2_rock_paper_scissors() {
+    check::<advent_2_rock_paper_scissors::RockPaperScissors>(50, BOTH);
+}
+
+#[test]
+fn day_3_rucksack() {
+    check::<advent_3_rucksack::RucksackReorganization>(10, BOTH);
+}
+
+#[test]
+fn day_4_clean_storage() {
+    check::<advent_4_clean_storage::CampCleanup>(50, BOTH);
+}
+
+#[test]
+fn day_5_crates() {
+    check::<advent_5_crates::SupplyStacks>(50, BOTH);
+}
+
+#[test]
+fn day_6_tuning_trouble() {
+    check::<advent_6_tuning_trouble::TuningTrouble>(200, BOTH);
+}
+
+#[test]
+fn day_7_no_space_left_on_device() {
+    check::<advent_7_no_space_left_on_device::NoSpaceLeftOnDevice>(20, BOTH);
+}
+
+#[test]
+fn day_8_treetop_tree_house() {
+    check::<advent_8::TreetopTreeHouse>(10, BOTH);
+}
+
+#[test]
+fn day_9_rope_bridge() {
+    check::<advent_9_rope_bridge::RopeBridge>(50, BOTH);
+}
+
+/// The program always fills the fixed screen, the size is ignored.
+#[test]
+fn day_10_cathode_ray_tube() {
+    check::<advent_10_cathode_ray_tube::CathodeRayTube>(0, BOTH);
+}
+
+#[test]
+fn day_11_monkey_in_the_middle() {
+    check::<advent_11_monkey_in_the_middle::MonkeyInTheMiddle>(8, BOTH);
+}
+
+#[test]
+fn day_12_hill_climbing_algorithm() {
+    check::<advent_12_hill_climbing_algorithm::HillClimbingAlgorithm>(8, BOTH);
+}
+
+#[test]
+fn day_13_distress_signal() {
+    check::<advent_13_distress_signal::DistressSignal>(10, BOTH);
+}
+
+#[test]
+fn day_14_regolith_reservoir() {
+    check::<advent_14_regolith_reservoir::RegolithReservoir>(10, BOTH);
+}
+
+#[test]
+fn day_15_beacon_exclusion_zone() {
+    check::<advent_15_beacon_exclusion_zone::BeaconExclusionZone>(5, BOTH);
+}
+
+/// Part 2 is left out, it takes minutes even on small cave systems.
+#[test]
+fn day_16_proboscidea_volcanium() {
+    check::<advent_16_proboscidea_volcanium::ProboscideaVolcanium>(8, &[Part::One]);
+}
+
+#[test]
+fn day_17_pyroclastic_flow() {
+    check::<advent_17_pyroclastic_flow::PyroclasticFlow>(40, BOTH);
+}
+
+#[test]
+fn day_18_boiling_boulders() {
+    check::<advent_18_boiling_boulders::BoilingBoulders>(50, BOTH);
+}
+
+#[test]
+fn day_19_not_enough_minerals() {
+    check::<advent_19_not_enough_minerals::NotEnoughMinerals>(1, BOTH);
+}
+
+#[test]
+fn day_20_grove_positioning_system() {
+    check::<advent_20_grove_positioning_system::GrovePositioningSystem>(30, BOTH);
+}
+
+#[test]
+fn day_21_monkey_match() {
+    check::<advent_21_monkey_match::MonkeyMath>(60, BOTH);
+}
+
+#[test]
+fn day_22_monkey_map() {
+    check::<advent_22_monkey_map::MonkeyMap>(6, BOTH);
+}
+
+#[test]
+fn day_23_unstable_diffusion() {
+    check::<advent_23_unstable_diffusion::UnstableDiffusion>(8, BOTH);
+}
+
+#[test]
+fn day_24_blizzard_basin() {
+    check::<advent_24_blizzard_basin::BlizzardBasin>(4, BOTH);
+}
+
+#[test]
+fn day_25_full_of_hot_air() {
+    check::<advent_25_full_of_hot_air::FullOfHotAir>(10, BOTH);
+}
+
+#[test]
+fn generated_directory_runs_in_batch() {
+    let dir = std::env::temp_dir().join(format!("aoc-generate-test-{}", std::process::id()));
+    let aoc = || Command::new(env!("CARGO_BIN_EXE_aoc"));
+
+    let generated = aoc().args(["generate", "--day", "1", "--seed", "5", "--count", "3", "--size", "10", "--output"]).arg(&dir).output().unwrap();
+    let batch = aoc().args(["batch", "--day", "1", "--input"]).arg(&dir).output().unwrap();
+    let mut names: Vec<String> = fs::read_dir(&dir).unwrap().map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned()).collect();
+    fs::remove_dir_all(&dir).unwrap();
+
+    names.sort();
+    assert!(generated.status.success(), "{}", String::from_utf8_lossy(&generated.stderr));
+    assert_eq!(names, ["input-5.txt", "input-6.txt", "input-7.txt"]);
+    assert!(batch.status.success(), "{}", String::from_utf8_lossy(&batch.stdout));
+}
@@ -0,0 +1,58 @@
+//! Shared points and directions: arithmetic, distances and turns.
+
+use aoc_core::geometry::{Direction, Point2, Point3, Turn};
+
+#[test]
+fn distances_between_points() {
+    let a = Point2::new(2i64, 18);
+    let b = Point2::new(-2, 15);
+
+    assert_eq!(a.manhattan(b), 7);
+    assert_eq!(a.chebyshev(b), 4);
+    assert_eq!(Point3::new(1, 1, 1).manhattan(Point3::new(3, -1, 1)), 4);
+}
+
+#[test]
+fn arithmetic_on_points() {
+    let mut point = Point2::new(3, -4);
+    point += Point2::new(1, 1);
+
+    assert_eq!(point, Point2::new(4, -3));
+    assert_eq!(point - Point2::new(4, 0), Point2::new(0, -3));
+    assert_eq!(-point * 2, Point2::new(-8, 6));
+    assert_eq!((point - Point2::origin()).signum(), Point2::new(1, -1));
+}
+
+#[test]
+fn turns_go_round_the_compass() {
+    assert_eq!(Direction::North.turn(Turn::Right), Direction::East);
+    assert_eq!(Direction::North.turn(Turn::Left), Direction::West);
+    assert_eq!(Direction::West.turn_right(), Direction::North);
+    assert_eq!(Direction::East.reverse(), Direction::West);
+}
+
+#[test]
+fn rotating_an_offset_follows_turning_its_direction() {
+    for direction in Direction::ALL {
+        assert_eq!(direction.offset::<i32>().rotate_right(), direction.turn_right().offset());
+        assert_eq!(direction.offset::<i32>().rotate_left(), direction.turn_left().offset());
+    }
+}
+
+#[test]
+fn neighbours_are_distinct_and_adjacent() {
+    let point = Point2::new(5, 5);
+    let neighbours = point.neighbours8();
+
+    assert!(neighbours.iter().all(|&neighbour| point.chebyshev(neighbour) == 1));
+    assert_eq!(neighbours[..2], [Point2::new(5, 4), Point2::new(6, 4)]);
+    assert!(Point3::new(0, 0, 0).neighbours6().iter().all(|&side| side.manhattan(Point3::origin()) == 1));
+}
+
+#[test]
+fn arrows_round_trip() {
+    for arrow in ['^', '>', 'v', '<'] {
+        assert_eq!(Direction::from_arrow(arrow).map(Direction::arrow), Some(arrow));
+    }
+    assert_eq!(Direction::from_arrow('#'), None);
+}
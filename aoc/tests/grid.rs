@@ -0,0 +1,84 @@
+//! The shared grid: parsing char maps, bounding boxes, neighbours, row and
+//! column views and rendering.
+
+use aoc_core::geometry::Point2;
+use aoc_core::grid::{Bounds, Grid};
+
+fn tile(c: char) -> Option<char> {
+    c.is_ascii_alphanumeric().then_some(c)
+}
+
+#[test]
+fn parse_skips_empty_characters() {
+    let grid = Grid::parse(".a.\nb..\n..c.\n", &['.'], "a letter", tile).unwrap();
+
+    assert_eq!(grid.len(), 3);
+    assert_eq!(grid.get(Point2::new(1, 0)), Some(&'a'));
+    assert_eq!(grid.get(Point2::new(0, 0)), None);
+    assert!(grid.is_inside(Point2::new(3, 2)));
+    assert_eq!(grid.bounds(), Some(Bounds { min: Point2::new(0, 0), max: Point2::new(2, 2) }));
+}
+
+#[test]
+fn parse_rectangle_reports_short_rows() {
+    let error = Grid::parse_rectangle("abc\nde\n", "letters", "a letter", tile).unwrap_err();
+
+    assert_eq!((error.line, error.found.as_str()), (2, "de"));
+    assert_eq!(error.expected, "a row of 3 letters");
+}
+
+#[test]
+fn parse_rectangle_reports_unknown_characters() {
+    let error = Grid::parse_rectangle("abc\na-c\n", "letters", "a letter", tile).unwrap_err();
+
+    assert_eq!((error.line, error.column), (2, 2));
+    assert_eq!(error.expected, "a letter");
+}
+
+#[test]
+fn sparse_bounds_shrink_when_an_edge_value_is_removed() {
+    let mut grid = Grid::sparse();
+    grid.insert(Point2::new(-2, 5), 'a');
+    grid.insert(Point2::new(3, 1), 'b');
+    grid.insert(Point2::new(0, 2), 'c');
+    assert_eq!(grid.bounds(), Some(Bounds { min: Point2::new(-2, 1), max: Point2::new(3, 5) }));
+
+    grid.remove(Point2::new(-2, 5));
+    assert_eq!(grid.bounds(), Some(Bounds { min: Point2::new(0, 1), max: Point2::new(3, 2) }));
+    assert_eq!(grid.bounds().unwrap().area(), 8);
+
+    grid.remove(Point2::new(3, 1));
+    grid.remove(Point2::new(0, 2));
+    assert_eq!(grid.bounds(), None);
+    assert!(grid.is_empty());
+}
+
+#[test]
+fn neighbours_stay_inside_a_dense_grid() {
+    let grid: Grid<()> = Grid::dense(3, 3);
+
+    assert_eq!(grid.neighbours4(Point2::new(0, 0)).collect::<Vec<_>>(), [Point2::new(1, 0), Point2::new(0, 1)]);
+    assert_eq!(grid.neighbours8(Point2::new(1, 1)).count(), 8);
+    assert_eq!(grid.neighbours8(Point2::new(2, 2)).count(), 3);
+}
+
+#[test]
+fn rows_and_columns_walk_both_ways() {
+    let grid = Grid::parse("ab.\n..c\n.de\n", &['.'], "a letter", tile).unwrap();
+    let values = |cells: Vec<(_, &char)>| cells.into_iter().map(|(_, &c)| c).collect::<String>();
+
+    assert_eq!(values(grid.row(2).collect()), "de");
+    assert_eq!(values(grid.row(2).rev().collect()), "ed");
+    assert_eq!(values(grid.column(2).collect()), "ce");
+    assert_eq!(grid.column(0).next_back().map(|(position, _)| position), Some(Point2::new(0, 0)));
+}
+
+#[test]
+fn render_draws_the_extent() {
+    let mut grid = Grid::sparse();
+    grid.insert(Point2::new(10, 10), '#');
+    grid.insert(Point2::new(12, 11), '#');
+
+    let map = grid.render(|_, value| *value.unwrap_or(&'.'));
+    assert_eq!(map, "#..\n..#\n");
+}
@@ -0,0 +1,42 @@
+//! Input paths: files, `-` for stdin and directories of inputs.
+
+use std::fs;
+use std::path::PathBuf;
+
+use aoc_core::cli::input_path;
+use aoc_core::input::input_files;
+use aoc_core::read_input;
+
+fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+#[test]
+fn input_path_skips_option_values() {
+    assert_eq!(input_path(args(&["--part", "1", "--set", "day9.knots=2"])).unwrap(), None);
+    assert_eq!(input_path(args(&["--part", "1", "inputs/big.txt"])).unwrap(), Some(PathBuf::from("inputs/big.txt")));
+    assert_eq!(input_path(args(&["-", "-q"])).unwrap(), Some(PathBuf::from("-")));
+    assert_eq!(input_path(args(&["--input", "a.txt"])).unwrap(), Some(PathBuf::from("a.txt")));
+    assert!(input_path(args(&["a.txt", "b.txt"])).is_err());
+}
+
+#[test]
+fn missing_input_is_named() {
+    let error = read_input("no/such/input.txt").unwrap_err();
+
+    assert_eq!(error.to_string(), "no/such/input.txt does not exist");
+}
+
+#[test]
+fn directory_lists_visible_files_in_order() {
+    let dir = std::env::temp_dir().join(format!("aoc-input-test-{}", std::process::id()));
+    fs::create_dir_all(dir.join("nested")).unwrap();
+    for name in ["b.txt", "a.txt", ".hidden"] {
+        fs::write(dir.join(name), "1\n").unwrap();
+    }
+
+    let files = input_files(&dir);
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(files.unwrap(), vec![dir.join("a.txt"), dir.join("b.txt")]);
+}
@@ -0,0 +1,30 @@
+//! `--format json` records must stay stable: scripts read them instead of
+//! scraping the text output.
+
+use std::time::Duration;
+
+use aoc_core::format::part_json;
+use aoc_core::json::Json;
+use aoc_core::{Answer, Part, Unsolved};
+
+#[test]
+fn answer_record() {
+    let json = part_json(14, Part::One, &Ok(Answer::from(24)), Duration::from_millis(2));
+
+    assert_eq!(json.to_string(), r#"{"day":14,"part":1,"answer":24,"elapsed":0.002}"#);
+}
+
+#[test]
+fn text_answer_record_round_trips() {
+    let json = part_json(10, Part::Two, &Ok(Answer::from("##..\n#..#")), Duration::ZERO);
+    let parsed = Json::parse(&json.to_string()).unwrap();
+
+    assert_eq!(parsed.get("answer").and_then(Json::as_str), Some("##..\n#..#"));
+}
+
+#[test]
+fn unsolved_record() {
+    let json = part_json(24, Part::Two, &Err(Box::new(Unsolved(Part::Two))), Duration::ZERO);
+
+    assert_eq!(json.to_string(), r#"{"day":24,"part":2,"answer":null,"elapsed":0,"error":"Part 2 is not solved yet","unsolved":true}"#);
+}
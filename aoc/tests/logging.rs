@@ -0,0 +1,45 @@
+//! Log level selection shared by the runner and the day binaries.
+
+use std::process::Command;
+
+use aoc_core::log::{self, Level};
+
+fn level(args: &[&str]) -> Level {
+    Level::from_args(args.iter().map(|arg| arg.to_string())).unwrap()
+}
+
+#[test]
+fn flags_select_level() {
+    assert_eq!(level(&["--part", "1"]), Level::Normal);
+    assert_eq!(level(&["-q"]), Level::Quiet);
+    assert_eq!(level(&["--verbose"]), Level::Verbose);
+    assert_eq!(level(&["-vv"]), Level::Trace);
+    assert_eq!(level(&["--log", "trace", "-q"]), Level::Quiet);
+}
+
+#[test]
+fn unknown_level_is_rejected() {
+    let error = Level::from_args(["--log".to_string(), "loud".to_string()]).unwrap_err();
+
+    assert_eq!(error.to_string(), "Invalid log level 'loud', possible values 'quiet', 'normal', 'verbose', 'trace'");
+}
+
+#[test]
+fn quiet_silences_everything() {
+    log::set_level(Level::Quiet);
+    assert!(Level::ALL.iter().all(|&level| !log::enabled(level)));
+
+    log::set_level(Level::Verbose);
+    assert!(log::enabled(Level::Normal) && log::enabled(Level::Verbose) && !log::enabled(Level::Trace));
+}
+
+#[test]
+fn normal_runs_print_only_answers_to_stdout() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc")).args(["run", "--day", "1"]).output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert!(output.status.success(), "{}", stderr);
+    assert!(stdout.lines().all(|line| line.starts_with("Day 1 part ")), "{}", stdout);
+    assert!(stderr.contains("Day 1 input parsed"), "{}", stderr);
+}
@@ -0,0 +1,50 @@
+//! Malformed inputs must come back as a `ParseError` pointing at the
+//! offending text instead of panicking.
+
+use aoc_core::{ParseError, Puzzle};
+
+fn parse_error<P: Puzzle>(input: &str) -> ParseError {
+    let error = match P::parse(input) {
+        Ok(_) => panic!("Input should not parse:\n{}", input),
+        Err(e) => e,
+    };
+
+    match error.downcast::<ParseError>() {
+        Ok(e) => *e,
+        Err(e) => panic!("Expected a parse error, got: {}", e),
+    }
+}
+
+#[test]
+fn day_1_calories_not_a_number() {
+    let error = parse_error::<advent_1_calories::CalorieCounting>("1000\n2000\n\n30x0\n");
+
+    assert_eq!((error.line, error.column, error.found.as_str()), (4, 1, "30x0"));
+}
+
+#[test]
+fn day_4_clean_storage_bad_separator() {
+    let error = parse_error::<advent_4_clean_storage::CampCleanup>("2-4,6-8\n2-4;6-8\n");
+
+    assert_eq!((error.line, error.column, error.found.as_str()), (2, 1, "2-4;6-8"));
+}
+
+#[test]
+fn day_13_distress_signal_unclosed_list() {
+    let error = parse_error::<advent_13_distress_signal::DistressSignal>("[1,[2,3]]\n[1,2\n");
+
+    assert_eq!((error.line, error.column, error.found.as_str()), (2, 5, ""));
+    assert_eq!(error.render(), "\
+error: expected ',' or ']', found end of line
+ --> line 2, column 5
+  |
+2 | [1,2
+  |     ^");
+}
+
+#[test]
+fn day_14_regolith_reservoir_diagonal_path() {
+    let error = parse_error::<advent_14_regolith_reservoir::RegolithReservoir>("498,4 -> 498,6 -> 496,7\n");
+
+    assert_eq!((error.line, error.column, error.found.as_str()), (1, 19, "496,7"));
+}
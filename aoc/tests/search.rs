@@ -0,0 +1,97 @@
+//! Shared graph searches: costs, rebuilt paths and several starts.
+
+use aoc_core::geometry::Point2;
+use aoc_core::grid::Grid;
+use aoc_core::search;
+
+const MAZE: &str = "\
+S.#.....
+.##.###.
+....#E..
+.####...
+";
+
+fn maze() -> Grid<char> {
+    Grid::parse(MAZE, &['#'], "a tile", Some).unwrap()
+}
+
+fn open(maze: &Grid<char>, position: Point2) -> Vec<Point2> {
+    maze.neighbours4(position).filter(|&next| maze.contains(next)).collect()
+}
+
+#[test]
+fn bfs_finds_the_shortest_path() {
+    let maze = maze();
+    let start = maze.find(|&c| c == 'S').unwrap();
+    let end = maze.find(|&c| c == 'E').unwrap();
+
+    let paths = search::bfs_until([start], |&position| open(&maze, position), |&position| position == end);
+    let path = paths.path(&end).unwrap();
+
+    assert_eq!(paths.goal(), Some(&end));
+    assert_eq!(paths.goal_cost(), Some(15));
+    assert_eq!(path.len(), 16);
+    assert_eq!((path[0], path[15]), (start, end));
+    assert!(path.windows(2).all(|step| step[0].manhattan(step[1]) == 1));
+}
+
+#[test]
+fn bfs_from_several_starts_measures_from_the_nearest() {
+    let maze = maze();
+    let paths = search::bfs([Point2::new(0, 0), Point2::new(7, 0)], |&position| open(&maze, position));
+
+    assert_eq!(paths.cost(&Point2::new(7, 2)), Some(2));
+    assert_eq!(paths.cost(&Point2::new(0, 3)), Some(3));
+    assert_eq!(paths.path(&Point2::new(7, 0)), Some(vec![Point2::new(7, 0)]));
+    assert_eq!(paths.len(), maze.len());
+}
+
+#[test]
+fn unreachable_nodes_have_no_cost() {
+    let paths = search::bfs_until([0], |&n: &i32| if n < 3 { vec![n + 1] } else { vec![] }, |&n| n == 10);
+
+    assert_eq!(paths.goal(), None);
+    assert_eq!(paths.cost(&10), None);
+    assert_eq!(paths.path(&10), None);
+    assert_eq!(paths.cost(&3), Some(3));
+}
+
+#[test]
+fn dijkstra_prefers_cheap_steps_over_few_steps() {
+    // 0 -> 3 directly costs 10, through 1 and 2 it costs 3
+    let edges = |&node: &u8| match node {
+        0 => vec![(1, 1), (3, 10)],
+        1 => vec![(2, 1)],
+        2 => vec![(3, 1)],
+        _ => vec![],
+    };
+
+    let paths = search::dijkstra([0u8], edges, |&node| node == 3);
+
+    assert_eq!(paths.goal_cost(), Some(3u32));
+    assert_eq!(paths.path(&3), Some(vec![0, 1, 2, 3]));
+}
+
+#[test]
+fn astar_agrees_with_dijkstra() {
+    let maze = maze();
+    let start = maze.find(|&c| c == 'S').unwrap();
+    let end = maze.find(|&c| c == 'E').unwrap();
+    let steps = |&position: &Point2| open(&maze, position).into_iter().map(|next| (next, 1)).collect::<Vec<_>>();
+
+    let dijkstra = search::dijkstra([start], steps, |&position| position == end);
+    let astar = search::astar([start], steps, |&position| position.manhattan(end), |&position| position == end);
+
+    assert_eq!(astar.goal_cost(), dijkstra.goal_cost());
+    assert_eq!(astar.goal_cost(), Some(15));
+    assert!(astar.len() <= dijkstra.len());
+}
+
+#[test]
+fn flood_fill_stays_in_its_region() {
+    let maze = maze();
+    let region = search::flood_fill([Point2::new(7, 3)], |&position| open(&maze, position));
+
+    assert_eq!(region.len(), maze.len());
+    assert!(region.contains(&Point2::new(0, 0)));
+}
@@ -0,0 +1,148 @@
+//! `aoc serve` answers solve requests over HTTP on localhost, with parse
+//! errors and bad requests mapped to HTTP statuses.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::process::{Child, ChildStderr, Command, Stdio};
+
+use aoc_core::json::Json;
+
+/// The server on a free port, killed when dropped.
+struct Server {
+    child: Child,
+    port: u16,
+    // Kept open so the server can keep logging
+    _stderr: BufReader<ChildStderr>,
+}
+
+impl Server {
+    fn start() -> Server {
+        Server::start_with(&[])
+    }
+
+    /// The server started with extra command line arguments.
+    fn start_with(args: &[&str]) -> Server {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["serve", "--port", "0"])
+            .args(args)
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        let mut stderr = BufReader::new(child.stderr.take().unwrap());
+        let mut line = String::new();
+        stderr.read_line(&mut line).unwrap();
+        let port = line.trim().rsplit(':').next().and_then(|port| port.parse().ok())
+            .unwrap_or_else(|| panic!("Expected the listening address, got '{}'", line));
+
+        Server { child, port, _stderr: stderr }
+    }
+
+    /// Sends a raw request and splits the response into status code and body.
+    fn send(&self, request: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(("127.0.0.1", self.port)).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+        (status, body.to_string())
+    }
+
+    fn post(&self, path: &str, input: &str) -> (u16, Json) {
+        let request = format!("POST {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}", path, input.len(), input);
+        let (status, body) = self.send(&request);
+        (status, Json::parse(&body).unwrap())
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+const DAY_1_EXAMPLE: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+#[test]
+fn solves_posted_inputs() {
+    let server = Server::start();
+
+    let (status, json) = server.post("/day/1/part/1", DAY_1_EXAMPLE);
+    assert_eq!(status, 200);
+    assert_eq!(json.get("answer").and_then(Json::as_i64), Some(24000));
+    assert!(json.get("elapsed").is_some() && json.get("parse_elapsed").is_some());
+
+    let (status, json) = server.post("/day/1/part/2", DAY_1_EXAMPLE);
+    assert_eq!(status, 200);
+    assert_eq!(json.get("answer").and_then(Json::as_i64), Some(45000));
+}
+
+#[test]
+fn parse_errors_are_bad_requests() {
+    let server = Server::start();
+
+    let (status, json) = server.post("/day/20/part/1", "1\nx\n0\n");
+    assert_eq!(status, 400);
+    assert_eq!(json.get("line").and_then(Json::as_i64), Some(2));
+    assert_eq!(json.get("found").and_then(Json::as_str), Some("x"));
+    assert_eq!(json.get("expected").and_then(Json::as_str), Some("a number"));
+}
+
+#[test]
+fn failing_parts_have_their_own_status() {
+    let server = Server::start();
+
+    let (status, json) = server.post("/day/17/part/2", ">><\n");
+    assert_eq!(status, 501);
+    assert_eq!(json.get("answer"), Some(&Json::Null));
+
+    // Part 2 needs more elves than this
+    let (status, json) = server.post("/day/1/part/2", "1000\n");
+    assert_eq!(status, 422);
+    assert_eq!(json.get("answer"), Some(&Json::Null));
+
+    // Day 5 panics on purpose when a move takes more crates than it can
+    let (status, json) = server.post("/day/5/part/1", "[A]\n 1 \n\nmove 2 from 1 to 1\n");
+    assert_eq!(status, 500);
+    assert_eq!(json.get("panicked"), Some(&Json::Bool(true)));
+}
+
+#[test]
+fn other_requests_are_rejected() {
+    let server = Server::start();
+
+    assert_eq!(server.post("/day/26/part/1", "").0, 404);
+    assert_eq!(server.post("/day/1/part/3", "").0, 404);
+    assert_eq!(server.post("/answers", "").0, 404);
+
+    let (status, _) = server.send("GET /day/1/part/1 HTTP/1.1\r\nHost: localhost\r\n\r\n");
+    assert_eq!(status, 405);
+    let (status, _) = server.send("POST /day/1/part/1 HTTP/1.1\r\nHost: localhost\r\n\r\n");
+    assert_eq!(status, 411);
+    let (status, _) = server.send("hello\r\n\r\n");
+    assert_eq!(status, 400);
+}
+
+#[test]
+fn bad_settings_are_server_errors() {
+    let server = Server::start_with(&["--set", "day1.top=0"]);
+
+    let (status, json) = server.post("/day/1/part/2", DAY_1_EXAMPLE);
+    assert_eq!(status, 500);
+    assert_eq!(json.get("line"), None);
+}
+
+#[test]
+fn oversized_heads_are_rejected() {
+    let server = Server::start();
+    // One byte over the server's 8 KiB line limit, sent without a line
+    // break so that the server reads all of it
+    let too_long = "a".repeat((8 << 10) + 1);
+
+    assert_eq!(server.send(&too_long).0, 414);
+    assert_eq!(server.send(&format!("POST /day/1/part/1 HTTP/1.1\r\n{}", too_long)).0, 431);
+    assert_eq!(server.send(&format!("POST /day/1/part/1 HTTP/1.1\r\n{}", "X-Many: 1\r\n".repeat(101))).0, 431);
+}
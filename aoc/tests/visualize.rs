@@ -0,0 +1,82 @@
+//! Simulation frames of the visualized days on their examples, the frame
+//! limit and the text and asciicast exports.
+
+use aoc_core::json::Json;
+use aoc_core::visualize::{self, Frame};
+use aoc_core::Config;
+
+const DAY_9_EXAMPLE: &str = "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2\n";
+const DAY_14_EXAMPLE: &str = "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9\n";
+const DAY_23_SMALL_EXAMPLE: &str = ".....\n..##.\n..#..\n.....\n..##.\n.....\n";
+const DAY_24_EXAMPLE: &str = "#.######\n#>>.<^<#\n#.<..<<#\n#>v.><>#\n#<^v^^>#\n######.#\n";
+
+fn frames<P: visualize::Visualize>(input: &str, limit: usize) -> Vec<Frame> {
+    visualize::frames::<P>(input, &Config::default(), limit).unwrap()
+}
+
+#[test]
+fn rope_moves_one_step_per_frame() {
+    let frames = frames::<advent_9_rope_bridge::RopeBridge>(DAY_9_EXAMPLE, visualize::DEFAULT_LIMIT);
+
+    // The start, then every one of the 24 head steps
+    assert_eq!(frames.len(), 25);
+    assert!(frames[0].picture.contains('H'));
+    assert_eq!(frames[24].caption, "== R 2 == step 2, tail visited 1 positions");
+}
+
+#[test]
+fn sand_frames_end_when_it_flows_into_the_abyss() {
+    let frames = frames::<advent_14_regolith_reservoir::RegolithReservoir>(DAY_14_EXAMPLE, visualize::DEFAULT_LIMIT);
+
+    assert_eq!(frames.len(), 25);
+    assert_eq!(frames[24].caption, "Units of sand at rest: 24");
+    assert_eq!(frames[24].picture.matches('o').count(), 24);
+    assert!(frames[0].picture.starts_with(".......+....\n"));
+}
+
+#[test]
+fn elves_are_drawn_after_every_round() {
+    let frames = frames::<advent_23_unstable_diffusion::UnstableDiffusion>(DAY_23_SMALL_EXAMPLE, visualize::DEFAULT_LIMIT);
+
+    assert_eq!(frames.len(), 11);
+    assert_eq!(frames[0].caption, "== Initial State ==");
+    assert!(frames.iter().all(|frame| frame.picture.matches('#').count() == 5));
+}
+
+#[test]
+fn blizzard_journey_takes_one_frame_per_minute() {
+    let frames = frames::<advent_24_blizzard_basin::BlizzardBasin>(DAY_24_EXAMPLE, visualize::DEFAULT_LIMIT);
+
+    assert_eq!(frames.len(), 55);
+    assert_eq!(frames[18].caption, "Minute 18, trip 1 of 3");
+    assert!(frames[18].picture.ends_with("######E#\n"));
+    assert_eq!(frames[54].caption, "Minute 54, trip 3 of 3");
+}
+
+#[test]
+fn the_limit_stops_the_simulation() {
+    let frames = frames::<advent_17_pyroclastic_flow::PyroclasticFlow>(">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>\n", 5);
+
+    assert_eq!(frames.len(), 5);
+    assert_eq!(frames[0].caption, "Empty chamber");
+    assert!(frames[0].picture.ends_with("|.......|\n+-------+\n"));
+    assert!(frames[4].picture.contains("|..@@@@.|"));
+}
+
+#[test]
+fn exports_text_and_asciicast() {
+    let frames = frames::<advent_14_regolith_reservoir::RegolithReservoir>(DAY_14_EXAMPLE, 3);
+
+    let text = visualize::to_text(&frames);
+    assert!(text.starts_with("Scanned cave\n.......+....\n"));
+    assert_eq!(text.matches("Units of sand at rest").count(), 2);
+
+    let cast = visualize::to_asciicast(&frames, 4, "Day 14");
+    let lines: Vec<&str> = cast.lines().collect();
+    let header = Json::parse(lines[0]).unwrap();
+    assert_eq!(lines.len(), 4);
+    assert_eq!(header.get("version").and_then(Json::as_i64), Some(2));
+    assert_eq!(header.get("width").and_then(Json::as_i64), Some(24));
+    assert_eq!(header.get("height").and_then(Json::as_i64), Some(12));
+    assert!(lines[2].starts_with("[0.25,\"o\",\"\\u001b[H\\u001b[2JUnits of sand at rest: 1"));
+}
@@ -0,0 +1,55 @@
+//! `aoc run --watch` solves again on every edit of the input and shows how
+//! the answers changed.
+
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// Replaces the file in one step, so the watch never polls it half written.
+fn edit(path: &Path, contents: &str) {
+    let staged = path.with_extension("staged");
+    fs::write(&staged, contents).unwrap();
+    fs::rename(&staged, path).unwrap();
+}
+
+#[test]
+fn edits_are_solved_and_compared() {
+    let path = std::env::temp_dir().join(format!("aoc-watch-test-{}.txt", std::process::id()));
+    fs::write(&path, "1000\n2000\n\n3000\n").unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--day", "1", "--part", "1", "--watch", "--input"])
+        .arg(&path)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+
+    let (sender, lines) = mpsc::channel();
+    let stdout = BufReader::new(child.stdout.take().unwrap());
+    thread::spawn(move || stdout.lines().map_while(Result::ok).try_for_each(|line| sender.send(line)));
+    let next_line = || lines.recv_timeout(Duration::from_secs(10)).expect("The watch should print a line");
+
+    assert!(next_line().ends_with(": 3000"));
+
+    edit(&path, "1000\n2000\n\n3000\n\n5000\n");
+    assert!(next_line().ends_with("changed <=="));
+    assert!(next_line().ends_with(": 5000 (was 3000)"));
+
+    // A broken input is reported without ending the watch, and the next
+    // answer is compared against the last one that worked
+    edit(&path, "1000\nx\n");
+    assert!(next_line().ends_with("changed <=="));
+    thread::sleep(Duration::from_secs(1));
+    edit(&path, "5000\n");
+    assert!(next_line().ends_with("changed <=="));
+    assert!(next_line().ends_with(": 5000 (unchanged)"));
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+    fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,69 @@
+use std::fmt;
+
+use crate::json::Json;
+
+/// Result of a single puzzle part.
+///
+/// Most parts produce a number, a few (crate tops, CRT image, SNAFU numbers)
+/// produce text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! answer_from_signed {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Answer {
+                Answer::Number(value as i64)
+            }
+        })*
+    };
+}
+
+macro_rules! answer_from_unsigned {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Answer {
+                match i64::try_from(value) {
+                    Ok(n) => Answer::Number(n),
+                    Err(_) => Answer::Text(value.to_string()),
+                }
+            }
+        })*
+    };
+}
+
+answer_from_signed!(i32, i64);
+answer_from_unsigned!(u8, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Answer {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Answer {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl From<&Answer> for Json {
+    fn from(answer: &Answer) -> Json {
+        match answer {
+            Answer::Number(n) => Json::Integer(*n),
+            Answer::Text(s) => Json::String(s.clone()),
+        }
+    }
+}
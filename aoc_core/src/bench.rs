@@ -0,0 +1,76 @@
+use std::time::{Duration, Instant};
+
+use crate::puzzle::{self, Puzzle};
+use crate::{Config, Part, Result};
+
+/// Min, median and max of a set of timing samples.
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let middle = sorted.len() / 2;
+        let median = match sorted.len() {
+            0 => Duration::ZERO,
+            n if n % 2 == 0 => (sorted[middle - 1] + sorted[middle]) / 2,
+            _ => sorted[middle],
+        };
+
+        Stats {
+            min: sorted.first().copied().unwrap_or_default(),
+            median,
+            max: sorted.last().copied().unwrap_or_default(),
+        }
+    }
+}
+
+/// Timings of one part; a part that fails (for example an unsolved one) is
+/// not timed and keeps its error message instead.
+pub struct PartBench {
+    pub part: Part,
+    pub stats: std::result::Result<Stats, String>,
+}
+
+pub struct Bench {
+    pub iterations: usize,
+    pub parse: Stats,
+    pub parts: Vec<PartBench>,
+}
+
+/// Times parsing and every requested part separately over `iterations` runs.
+/// Each iteration parses the input again, so parse timings are as honest as
+/// the part ones.
+pub fn bench<P: Puzzle>(input: &str, parts: &[Part], config: &Config, iterations: usize) -> Result<Bench> {
+    let mut parse_samples = Vec::with_capacity(iterations);
+    let mut part_samples: Vec<std::result::Result<Vec<Duration>, String>> = parts.iter().map(|_| Ok(Vec::new())).collect();
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = puzzle::parse_configured::<P>(input, config)?;
+        parse_samples.push(start.elapsed());
+
+        for (part, samples) in parts.iter().zip(part_samples.iter_mut()) {
+            let Ok(timings) = samples else { continue };
+
+            let start = Instant::now();
+            match puzzle::solve_parsed::<P>(&parsed, *part) {
+                Ok(_) => timings.push(start.elapsed()),
+                Err(e) => *samples = Err(e.to_string()),
+            }
+        }
+    }
+
+    let parts = parts.iter().zip(part_samples).map(|(&part, samples)| PartBench {
+        part,
+        stats: samples.map(|timings| Stats::from_samples(&timings)),
+    }).collect();
+
+    Ok(Bench { iterations, parse: Stats::from_samples(&parse_samples), parts })
+}
@@ -0,0 +1,104 @@
+use std::path::{Path, PathBuf};
+use std::process;
+
+use crate::puzzle::{self, Puzzle};
+use crate::format::{self, Format};
+use crate::input::{self, input_files};
+use crate::log::{self, Level};
+use crate::{parse, read_input, Answer, Config, Part, Result, Unsolved};
+
+/// Options of the day binaries that take a value, so the value is not
+/// mistaken for the input path.
+const VALUE_OPTIONS: [&str; 6] = ["--part", "-p", "--config", "--set", "--format", "--log"];
+
+/// Entry point shared by the day binaries: reads the input, runs the parts
+/// selected on the command line with the settings from `--config` and `--set`
+/// and prints every answer after its label, or as JSON with `--format json`.
+/// Diagnostics follow the `--quiet`/`--verbose`/`--trace` level.
+///
+/// The input is the path given on the command line (a file, `-` for stdin or
+/// a directory to solve every file in it), `default_input` otherwise.
+/// Errors are reported on stderr and exit with status 1.
+pub fn run_day<P: Puzzle>(default_input: impl AsRef<Path>, labels: [&str; 2]) {
+    if let Err(e) = try_run_day::<P>(default_input.as_ref(), labels) {
+        eprintln!("{}", parse::report(&e));
+        process::exit(1);
+    }
+}
+
+fn try_run_day<P: Puzzle>(default_input: &Path, labels: [&str; 2]) -> Result<()> {
+    log::set_level(Level::selected()?);
+    let config = Config::from_args(std::env::args().skip(1))?;
+    let format = Format::selected()?;
+    let parts = Part::selected()?;
+
+    let path = input_path(std::env::args().skip(1))?.unwrap_or_else(|| default_input.to_path_buf());
+    let files = input_files(&path)?;
+    if files.len() == 1 && files[0] == path {
+        return solve_file::<P>(&path, &parts, &config, format, labels, false);
+    }
+
+    let mut failed = 0;
+    for file in &files {
+        if format == Format::Text {
+            println!("==> {} <==", file.display());
+        }
+        if let Err(e) = solve_file::<P>(file, &parts, &config, format, labels, true) {
+            eprintln!("{}", parse::report(&e));
+            failed += 1;
+        }
+    }
+
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{} of {} inputs in {} failed", failed, files.len(), path.display()).into()),
+    }
+}
+
+fn solve_file<P: Puzzle>(path: &Path, parts: &[Part], config: &Config, format: Format, labels: [&str; 2], name_input: bool) -> Result<()> {
+    let input = read_input(path)?;
+    let run = puzzle::run::<P>(&input, parts, config).map_err(|e| parse::in_file(e, path))?;
+
+    for part_run in run.parts {
+        let label = labels[part_run.part.number() as usize - 1];
+
+        if format == Format::Json {
+            let mut json = format::part_json(P::DAY, part_run.part, &part_run.answer, part_run.elapsed);
+            if name_input {
+                json = json.field("input", input::display_name(path));
+            }
+            println!("{}", json);
+        }
+
+        match part_run.answer {
+            Err(e) if !e.is::<Unsolved>() => return Err(e),
+            _ if format == Format::Json => (),
+            Ok(Answer::Text(text)) if text.contains('\n') => println!("{}:\n{}", label, text),
+            Ok(answer) => println!("{}: {}", label, answer),
+            Err(e) => println!("{}", e),
+        }
+    }
+
+    Ok(())
+}
+
+/// Input path given as `--input <PATH>` or as the only positional argument.
+pub fn input_path(args: impl IntoIterator<Item = String>) -> Result<Option<PathBuf>> {
+    let mut path = None;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "--input" || arg == "-i" {
+            path = Some(PathBuf::from(args.next().ok_or("Missing value for --input")?));
+        } else if VALUE_OPTIONS.contains(&arg.as_str()) {
+            args.next();
+        } else if arg == input::STDIN || !arg.starts_with('-') {
+            if path.is_some() {
+                return Err(format!("Unexpected argument '{}', only one input path can be given", arg).into());
+            }
+            path = Some(PathBuf::from(arg));
+        }
+    }
+
+    Ok(path)
+}
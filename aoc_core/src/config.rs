@@ -0,0 +1,206 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::parse::{self, numbered_lines};
+use crate::{read_input, ParseError, Result};
+
+/// Config file picked up from the workspace root when no `--config` is given.
+pub const DEFAULT_CONFIG: &str = "aoc.toml";
+
+/// Per-day tunables, read from a small TOML subset: one `[dayN]` table per
+/// day holding integer settings.
+///
+/// ```toml
+/// [day15]
+/// fill_row_y = 10
+/// max_coordinate = 20
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    days: BTreeMap<u8, BTreeMap<String, i64>>,
+}
+
+impl Config {
+    pub fn default_path() -> PathBuf {
+        let workspace_root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        workspace_root.join(DEFAULT_CONFIG)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Config> {
+        let path = path.as_ref();
+        let text = read_input(path)?;
+        Config::parse(&text).map_err(|e| parse::in_file(Box::new(e), path))
+    }
+
+    pub fn parse(text: &str) -> std::result::Result<Config, ParseError> {
+        let mut config = Config::default();
+        let mut day = None;
+
+        for (line_number, line) in numbered_lines(text) {
+            let content = line.split('#').next().unwrap().trim();
+            if content.is_empty() {
+                continue;
+            }
+
+            if let Some(table) = content.strip_prefix('[') {
+                let name = table.strip_suffix(']')
+                    .ok_or_else(|| ParseError::new(line_number, line, content, "a table header like '[day15]'"))?;
+                day = Some(day_number(name.trim())
+                    .ok_or_else(|| ParseError::new(line_number, line, name, "a day table 'day1' to 'day25'"))?);
+                continue;
+            }
+
+            let Some(day) = day else {
+                return Err(ParseError::new(line_number, line, content, "a day table like '[day15]' before any setting"));
+            };
+            let (key, value) = content.split_once('=')
+                .ok_or_else(|| ParseError::new(line_number, line, content, "a setting like 'rounds = 10'"))?;
+            let (key, value) = (key.trim(), value.trim());
+
+            if !is_key(key) {
+                return Err(ParseError::new(line_number, line, key, "a setting name of letters, digits and '_'"));
+            }
+            let value = value.replace('_', "").parse::<i64>()
+                .map_err(|_| ParseError::new(line_number, line, value, "an integer value"))?;
+
+            config.days.entry(day).or_default().insert(key.to_string(), value);
+        }
+
+        Ok(config)
+    }
+
+    /// Config for the command line: the file given with `--config`, else the
+    /// workspace `aoc.toml` when there is one, then every `--set
+    /// day15.fill_row_y=10` override on top.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Config> {
+        let mut path = None;
+        let mut overrides = Vec::new();
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--config" => path = Some(PathBuf::from(args.next().ok_or(ConfigError::missing_value(&arg))?)),
+                "--set" => overrides.push(args.next().ok_or(ConfigError::missing_value(&arg))?),
+                _ => (),
+            }
+        }
+
+        Config::with_overrides(path, &overrides)
+    }
+
+    /// Loads `path`, or the default config when it exists, and applies the
+    /// `dayN.key=value` overrides.
+    pub fn with_overrides(path: Option<PathBuf>, overrides: &[String]) -> Result<Config> {
+        let mut config = match path {
+            Some(path) => Config::load(path)?,
+            None if Config::default_path().exists() => Config::load(Config::default_path())?,
+            None => Config::default(),
+        };
+
+        for assignment in overrides {
+            config.set(assignment)?;
+        }
+
+        Ok(config)
+    }
+
+    /// Applies a `dayN.key=value` override.
+    pub fn set(&mut self, assignment: &str) -> Result<()> {
+        let invalid = || ConfigError(format!("Invalid setting '{}', expected e.g. 'day15.fill_row_y=10'", assignment));
+
+        let (name, value) = assignment.split_once('=').ok_or_else(invalid)?;
+        let (day, key) = name.trim().split_once('.').ok_or_else(invalid)?;
+        let day = day_number(day).ok_or_else(invalid)?;
+        let value = value.trim().replace('_', "").parse::<i64>().map_err(|_| invalid())?;
+
+        if !is_key(key) {
+            return Err(Box::new(invalid()));
+        }
+
+        self.days.entry(day).or_default().insert(key.to_string(), value);
+        Ok(())
+    }
+
+    /// Whether any setting of `day` is configured.
+    pub fn has_settings(&self, day: u8) -> bool {
+        self.days.contains_key(&day)
+    }
+
+    pub fn day(&self, day: u8) -> DayConfig<'_> {
+        DayConfig { day, values: self.days.get(&day), read: RefCell::new(BTreeSet::new()) }
+    }
+}
+
+/// One day's settings. Remembers which ones the day asked for, so a setting
+/// nobody reads (usually a typo) is reported instead of silently ignored.
+pub struct DayConfig<'a> {
+    day: u8,
+    values: Option<&'a BTreeMap<String, i64>>,
+    read: RefCell<BTreeSet<&'static str>>,
+}
+
+impl DayConfig<'_> {
+    /// The setting `key`, or `default` when it is not configured.
+    pub fn get<T: TryFrom<i64>>(&self, key: &'static str, default: T) -> Result<T> {
+        self.read.borrow_mut().insert(key);
+
+        match self.values.and_then(|values| values.get(key)) {
+            Some(&value) => T::try_from(value)
+                .map_err(|_| ConfigError(format!("Setting day{}.{} = {} is out of range", self.day, key, value)).into()),
+            None => Ok(default),
+        }
+    }
+
+    /// Like [`DayConfig::get`], rejecting configured values below `min`.
+    pub fn get_at_least<T: TryFrom<i64>>(&self, key: &'static str, default: T, min: i64) -> Result<T> {
+        match self.values.and_then(|values| values.get(key)) {
+            Some(&value) if value < min => {
+                self.read.borrow_mut().insert(key);
+                Err(Box::new(ConfigError(format!("Setting day{}.{} = {} must be at least {}", self.day, key, value, min))))
+            }
+            _ => self.get(key, default),
+        }
+    }
+
+    /// Fails on the first configured setting the day never asked for.
+    pub fn check_unused(&self) -> Result<()> {
+        let read = self.read.borrow();
+        let unused = self.values.into_iter().flat_map(|values| values.keys()).find(|key| !read.contains(key.as_str()));
+
+        match unused {
+            Some(key) if read.is_empty() => Err(Box::new(ConfigError(format!("Unknown setting day{}.{}, day {} has no settings", self.day, key, self.day)))),
+            Some(key) => {
+                let known = read.iter().copied().collect::<Vec<&str>>().join(", ");
+                Err(Box::new(ConfigError(format!("Unknown setting day{}.{}, known settings: {}", self.day, key, known))))
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+fn day_number(name: &str) -> Option<u8> {
+    name.strip_prefix("day")?.parse::<u8>().ok().filter(|day| (1..=25).contains(day))
+}
+
+fn is_key(key: &str) -> bool {
+    !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[derive(Debug)]
+pub struct ConfigError(String);
+
+impl ConfigError {
+    fn missing_value(option: &str) -> ConfigError {
+        ConfigError(format!("Missing value for {}", option))
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -0,0 +1,27 @@
+/// Pulls the worked example input out of the exercise text quoted in the
+/// trailing `/* ... */` comment of a day's source file.
+///
+/// The example starts after the first line that introduces it ("For example,
+/// ... :") and runs until the puzzle text continues with a sentence. Blank
+/// lines around the example are dropped, blank lines inside it are kept.
+pub fn from_exercise(source: &str) -> Option<String> {
+    let comment = &source[source.rfind("/*")?..];
+
+    let mut lines = comment.lines().skip_while(|line| !(line.contains("For example") && line.ends_with(':')));
+    lines.next()?;
+
+    let example: Vec<&str> = lines.take_while(|line| !is_sentence(line) && *line != "*/").collect();
+    let first = example.iter().position(|line| !line.trim().is_empty())?;
+    let last = example.iter().rposition(|line| !line.trim().is_empty())?;
+
+    Some(example[first..=last].join("\n") + "\n")
+}
+
+fn is_sentence(line: &str) -> bool {
+    let mut chars = line.chars();
+
+    return match (chars.next(), chars.next()) {
+        (Some(first), Some(second)) => first.is_uppercase() && second.is_lowercase() && line.contains(' '),
+        _ => false,
+    };
+}
@@ -0,0 +1,90 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::json::Json;
+use crate::{Answer, Part, Result, Unsolved};
+
+/// How answers are written to stdout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// Labelled answers for reading in a terminal.
+    #[default]
+    Text,
+    /// One JSON object per part and line, see [`part_json`].
+    Json,
+}
+
+impl Format {
+    /// Format requested on the command line with `--format <text|json>`,
+    /// text when the flag is missing.
+    pub fn selected() -> Result<Format> {
+        Format::from_args(std::env::args().skip(1))
+    }
+
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Format> {
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if arg == "--format" {
+                let value = args.next().ok_or(FormatError(String::new()))?;
+                return Ok(value.parse()?);
+            }
+        }
+
+        Ok(Format::Text)
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Format::Text => write!(f, "text"),
+            Format::Json => write!(f, "json"),
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = FormatError;
+
+    fn from_str(s: &str) -> std::result::Result<Format, FormatError> {
+        match s.trim() {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            other => Err(FormatError(other.to_string())),
+        }
+    }
+}
+
+/// Record for one solved part:
+/// `{"day":14,"part":1,"answer":737,"elapsed":0.000412}`.
+///
+/// Numeric answers are JSON numbers, the rest strings, and `elapsed` is in
+/// seconds. A part that failed has a `null` answer and an `error` message.
+pub fn part_json(day: u8, part: Part, answer: &Result<Answer>, elapsed: Duration) -> Json {
+    let json = Json::object()
+        .field("day", day)
+        .field("part", part.number());
+
+    match answer {
+        Ok(answer) => json
+            .field("answer", answer)
+            .field("elapsed", elapsed.as_secs_f64()),
+        Err(e) => json
+            .field("answer", Json::Null)
+            .field("elapsed", elapsed.as_secs_f64())
+            .field("error", e.to_string())
+            .field("unsolved", e.is::<Unsolved>()),
+    }
+}
+
+#[derive(Debug)]
+pub struct FormatError(String);
+
+impl std::error::Error for FormatError {}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid format '{}', possible values 'text', 'json'", self.0)
+    }
+}
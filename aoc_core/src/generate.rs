@@ -0,0 +1,81 @@
+//! Random puzzle inputs for stress tests and benchmarks.
+//!
+//! Every day implements [`Generate`], writing inputs in the exact format of
+//! the real puzzle input. What `size` means is up to the day (elves, lines,
+//! monkeys, the side of a grid...) and documented on its implementation. The
+//! same seed and size always give the same input.
+
+use std::ops::RangeInclusive;
+
+use crate::Puzzle;
+
+/// Small deterministic random number generator (SplitMix64), enough for
+/// input generation without pulling in a crate.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `0..n`; `n` must not be 0.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "Cannot pick below 0");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// Uniform value in the inclusive range.
+    pub fn between(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "Empty range {}..={}", start, end);
+        let span = (end - start) as u64 + 1;
+        start + (self.next_u64() % span) as i64
+    }
+
+    /// True with the given probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64 <= probability
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// Random character of `chars`.
+    pub fn char_of(&mut self, chars: &str) -> char {
+        let chars: Vec<char> = chars.chars().collect();
+        *self.pick(&chars)
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// A day that can write random, valid inputs for itself.
+pub trait Generate: Puzzle {
+    /// Size giving an input about as large as the real one.
+    const DEFAULT_SIZE: usize;
+
+    /// Writes an input of roughly `size` units; sizes below the smallest
+    /// meaningful input are rounded up.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// Input of `P` for `seed`, at `size` or the day's default size.
+pub fn generate<P: Generate>(seed: u64, size: Option<usize>) -> String {
+    P::generate(&mut Rng::new(seed), size.unwrap_or(P::DEFAULT_SIZE))
+}
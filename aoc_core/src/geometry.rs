@@ -0,0 +1,286 @@
+//! Points, directions and distances shared by the days moving around maps
+//! and through space.
+//!
+//! Points are generic over their [`Coordinate`] type, `i32` for maps and
+//! cubes, `i64` where sensors are millions of positions apart. Like
+//! [`crate::grid`], y grows downwards as the puzzle maps are drawn, so
+//! [`Direction::North`] decreases y and turning right is clockwise on screen.
+
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Signed integer a point can be made of.
+pub trait Coordinate:
+    Copy + Debug + Default + Ord + Hash
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+    + AddAssign + SubAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                const ZERO: $t = 0;
+                const ONE: $t = 1;
+
+                fn abs(self) -> $t {
+                    <$t>::abs(self)
+                }
+
+                fn signum(self) -> $t {
+                    <$t>::signum(self)
+                }
+            }
+        )*
+    };
+}
+
+coordinate!(i32, i64);
+
+/// Point on a plane, or the offset between two of them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T = i32> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coordinate> Point2<T> {
+    pub const fn new(x: T, y: T) -> Point2<T> {
+        Point2 { x, y }
+    }
+
+    pub fn origin() -> Point2<T> {
+        Point2::new(T::ZERO, T::ZERO)
+    }
+
+    /// Taxicab distance: steps needed moving only orthogonally.
+    pub fn manhattan(self, other: Point2<T>) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Chessboard distance: steps needed moving diagonally too.
+    pub fn chebyshev(self, other: Point2<T>) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Each coordinate reduced to -1, 0 or 1, a single step towards the
+    /// direction of this offset.
+    pub fn signum(self) -> Point2<T> {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Rotated a quarter turn clockwise around the origin.
+    pub fn rotate_right(self) -> Point2<T> {
+        Point2::new(-self.y, self.x)
+    }
+
+    /// Rotated a quarter turn anticlockwise around the origin.
+    pub fn rotate_left(self) -> Point2<T> {
+        Point2::new(self.y, -self.x)
+    }
+
+    /// Orthogonal neighbours: north, east, south, west.
+    pub fn neighbours4(self) -> [Point2<T>; 4] {
+        Direction::ALL.map(|direction| self + direction.offset())
+    }
+
+    /// Orthogonal and diagonal neighbours, clockwise from north.
+    pub fn neighbours8(self) -> [Point2<T>; 8] {
+        let [north, east, south, west] = Direction::ALL.map(|direction| direction.offset::<T>());
+        [north, north + east, east, south + east, south, south + west, west, north + west].map(|offset| self + offset)
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Point2<T> {
+        Point2 { x, y }
+    }
+}
+
+/// Point in space, or the offset between two of them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T = i32> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coordinate> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Point3<T> {
+        Point3 { x, y, z }
+    }
+
+    pub fn origin() -> Point3<T> {
+        Point3::new(T::ZERO, T::ZERO, T::ZERO)
+    }
+
+    pub fn manhattan(self, other: Point3<T>) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(self, other: Point3<T>) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs()).max((self.z - other.z).abs())
+    }
+
+    pub fn signum(self) -> Point3<T> {
+        Point3::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    /// The six points sharing a face with this one.
+    pub fn neighbours6(self) -> [Point3<T>; 6] {
+        let (zero, one) = (T::ZERO, T::ONE);
+        [
+            Point3::new(one, zero, zero), Point3::new(-one, zero, zero),
+            Point3::new(zero, one, zero), Point3::new(zero, -one, zero),
+            Point3::new(zero, zero, one), Point3::new(zero, zero, -one),
+        ].map(|offset| self + offset)
+    }
+
+    /// Smallest and largest value of each coordinate over `points`, `None`
+    /// when there are none.
+    pub fn bounds(points: impl IntoIterator<Item = Point3<T>>) -> Option<(Point3<T>, Point3<T>)> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+
+        Some(points.fold((first, first), |(min, max), point| (
+            Point3::new(min.x.min(point.x), min.y.min(point.y), min.z.min(point.z)),
+            Point3::new(max.x.max(point.x), max.y.max(point.y), max.z.max(point.z)),
+        )))
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Point3<T> {
+        Point3 { x, y, z }
+    }
+}
+
+macro_rules! point_ops {
+    ($point:ident { $($field:ident),* }) => {
+        impl<T: Coordinate> Add for $point<T> {
+            type Output = $point<T>;
+
+            fn add(self, other: $point<T>) -> $point<T> {
+                $point { $($field: self.$field + other.$field),* }
+            }
+        }
+
+        impl<T: Coordinate> Sub for $point<T> {
+            type Output = $point<T>;
+
+            fn sub(self, other: $point<T>) -> $point<T> {
+                $point { $($field: self.$field - other.$field),* }
+            }
+        }
+
+        impl<T: Coordinate> Neg for $point<T> {
+            type Output = $point<T>;
+
+            fn neg(self) -> $point<T> {
+                $point { $($field: -self.$field),* }
+            }
+        }
+
+        /// Scaled by a factor.
+        impl<T: Coordinate> Mul<T> for $point<T> {
+            type Output = $point<T>;
+
+            fn mul(self, factor: T) -> $point<T> {
+                $point { $($field: self.$field * factor),* }
+            }
+        }
+
+        impl<T: Coordinate> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: $point<T>) {
+                $(self.$field += other.$field;)*
+            }
+        }
+
+        impl<T: Coordinate> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: $point<T>) {
+                $(self.$field -= other.$field;)*
+            }
+        }
+    };
+}
+
+point_ops!(Point2 { x, y });
+point_ops!(Point3 { x, y, z });
+
+/// Compass direction on a map drawn with north at the top.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+/// Quarter turn to either side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    /// One step in this direction.
+    pub fn offset<T: Coordinate>(self) -> Point2<T> {
+        let (zero, one) = (T::ZERO, T::ONE);
+        match self {
+            Direction::North => Point2::new(zero, -one),
+            Direction::East => Point2::new(one, zero),
+            Direction::South => Point2::new(zero, one),
+            Direction::West => Point2::new(-one, zero),
+        }
+    }
+
+    pub fn turn(self, turn: Turn) -> Direction {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Direction drawn as `^`, `>`, `v` or `<`.
+    pub fn from_arrow(arrow: char) -> Option<Direction> {
+        match arrow {
+            '^' => Some(Direction::North),
+            '>' => Some(Direction::East),
+            'v' => Some(Direction::South),
+            '<' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<',
+        }
+    }
+}
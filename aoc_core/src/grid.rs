@@ -0,0 +1,308 @@
+//! Two dimensional maps shared by the grid days.
+//!
+//! A [`Grid`] stores one value per occupied position, either in a dense
+//! rectangle fixed at creation (tree heights, heightmaps, the blizzard valley)
+//! or sparsely for maps without a known size (the sand cave, the rock chamber).
+//! Positions are [`Point2`]s with x growing rightwards and y downwards, like
+//! the puzzle maps are drawn.
+
+use std::collections::HashMap;
+use std::ops::Index;
+
+use crate::geometry::Point2;
+use crate::ParseError;
+
+/// Position on a grid.
+pub type Position = Point2<i32>;
+
+/// Inclusive rectangle of positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: Position,
+    pub max: Position,
+}
+
+impl Bounds {
+    fn of(position: Position) -> Bounds {
+        Bounds { min: position, max: position }
+    }
+
+    pub fn contains(&self, Point2 { x, y }: Position) -> bool {
+        (self.min.x..=self.max.x).contains(&x) && (self.min.y..=self.max.y).contains(&y)
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.x - self.min.x + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.y - self.min.y + 1) as usize
+    }
+
+    pub fn area(&self) -> usize {
+        self.width() * self.height()
+    }
+
+    /// Every position in the rectangle, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let (min, max) = (self.min, self.max);
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point2::new(x, y)))
+    }
+
+    /// Draws the rectangle one character per position, each row ended by a
+    /// newline.
+    pub fn render(&self, mut tile: impl FnMut(Position) -> char) -> String {
+        let mut map = String::with_capacity((self.width() + 1) * self.height());
+        for position in self.positions() {
+            map.push(tile(position));
+            if position.x == self.max.x {
+                map.push('\n');
+            }
+        }
+        map
+    }
+
+    fn extend(&mut self, Point2 { x, y }: Position) {
+        self.min = Point2::new(self.min.x.min(x), self.min.y.min(y));
+        self.max = Point2::new(self.max.x.max(x), self.max.y.max(y));
+    }
+
+    fn on_edge(&self, Point2 { x, y }: Position) -> bool {
+        x == self.min.x || x == self.max.x || y == self.min.y || y == self.max.y
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Cells<T> {
+    Dense { width: usize, height: usize, cells: Vec<Option<T>> },
+    Sparse(HashMap<Position, T>),
+}
+
+/// Values on a 2D map, with the bounding box of the occupied positions kept
+/// up to date as values are inserted and removed.
+#[derive(Debug, Clone)]
+pub struct Grid<T> {
+    cells: Cells<T>,
+    bounds: Option<Bounds>,
+    len: usize,
+}
+
+impl<T> Grid<T> {
+    /// Empty grid covering `width` by `height` positions from (0, 0).
+    pub fn dense(width: usize, height: usize) -> Grid<T> {
+        let cells = (0..width * height).map(|_| None).collect();
+        Grid { cells: Cells::Dense { width, height, cells }, bounds: None, len: 0 }
+    }
+
+    /// Empty grid accepting any position.
+    pub fn sparse() -> Grid<T> {
+        Grid { cells: Cells::Sparse(HashMap::new()), bounds: None, len: 0 }
+    }
+
+    /// Dense grid of a map drawn with one character per position, as wide as
+    /// its longest line. Characters in `empty` leave their position empty,
+    /// any other is turned into a value by `tile`; a character `tile` does
+    /// not know is reported as not being `expected`.
+    pub fn parse(input: &str, empty: &[char], expected: &str, mut tile: impl FnMut(char) -> Option<T>) -> Result<Grid<T>, ParseError> {
+        let width = input.lines().map(|line| line.chars().count()).max().unwrap_or(0);
+        let mut grid = Grid::dense(width, input.lines().count());
+
+        for (y, line) in input.lines().enumerate() {
+            for (x, (i, c)) in line.char_indices().enumerate() {
+                if empty.contains(&c) {
+                    continue;
+                }
+                let value = tile(c).ok_or_else(|| ParseError::at_char(y + 1, line, i, expected))?;
+                grid.insert(Point2::new(x as i32, y as i32), value);
+            }
+        }
+
+        Ok(grid)
+    }
+
+    /// Like [`Grid::parse`] for maps where every position holds a value and
+    /// every row is as long as the first, at least one row of `rows` long.
+    pub fn parse_rectangle(input: &str, rows: &str, expected: &str, mut tile: impl FnMut(char) -> Option<T>) -> Result<Grid<T>, ParseError> {
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        if width == 0 {
+            return Err(ParseError::end_of_input(input, format!("a row of {}", rows)));
+        }
+
+        let mut grid = Grid::dense(width, input.lines().count());
+        for (y, line) in input.lines().enumerate() {
+            let row = line.char_indices()
+                .map(|(i, c)| tile(c).ok_or_else(|| ParseError::at_char(y + 1, line, i, expected)))
+                .collect::<Result<Vec<T>, ParseError>>()?;
+            if row.len() != width {
+                return Err(ParseError::new(y + 1, line, line, format!("a row of {} {}", width, rows)));
+            }
+
+            for (x, value) in row.into_iter().enumerate() {
+                grid.insert(Point2::new(x as i32, y as i32), value);
+            }
+        }
+
+        Ok(grid)
+    }
+
+    /// Positions a dense grid covers; the occupied ones of a sparse grid.
+    pub fn extent(&self) -> Option<Bounds> {
+        match &self.cells {
+            Cells::Dense { width: 0, .. } | Cells::Dense { height: 0, .. } => None,
+            Cells::Dense { width, height, .. } => Some(Bounds { min: Point2::origin(), max: Point2::new(*width as i32 - 1, *height as i32 - 1) }),
+            Cells::Sparse(_) => self.bounds,
+        }
+    }
+
+    /// Smallest rectangle holding every occupied position.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// Whether a value can be stored at `position`: inside a dense grid,
+    /// anywhere for a sparse one.
+    pub fn is_inside(&self, Point2 { x, y }: Position) -> bool {
+        match &self.cells {
+            Cells::Dense { width, height, .. } => x >= 0 && y >= 0 && (x as usize) < *width && (y as usize) < *height,
+            Cells::Sparse(_) => true,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        match &self.cells {
+            Cells::Dense { width, cells, .. } if self.is_inside(position) => cells[index(*width, position)].as_ref(),
+            Cells::Dense { .. } => None,
+            Cells::Sparse(cells) => cells.get(&position),
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        let inside = self.is_inside(position);
+        match &mut self.cells {
+            Cells::Dense { width, cells, .. } if inside => cells[index(*width, position)].as_mut(),
+            Cells::Dense { .. } => None,
+            Cells::Sparse(cells) => cells.get_mut(&position),
+        }
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        self.get(position).is_some()
+    }
+
+    /// Stores `value` at `position`, returning the value it replaces.
+    /// Panics outside a dense grid.
+    pub fn insert(&mut self, position: Position, value: T) -> Option<T> {
+        assert!(self.is_inside(position), "Position {:?} is outside the grid", position);
+        let previous = match &mut self.cells {
+            Cells::Dense { width, cells, .. } => cells[index(*width, position)].replace(value),
+            Cells::Sparse(cells) => cells.insert(position, value),
+        };
+
+        if previous.is_none() {
+            self.len += 1;
+            match &mut self.bounds {
+                Some(bounds) => bounds.extend(position),
+                None => self.bounds = Some(Bounds::of(position)),
+            }
+        }
+        previous
+    }
+
+    pub fn remove(&mut self, position: Position) -> Option<T> {
+        let inside = self.is_inside(position);
+        let removed = match &mut self.cells {
+            Cells::Dense { width, cells, .. } if inside => cells[index(*width, position)].take(),
+            Cells::Dense { .. } => None,
+            Cells::Sparse(cells) => cells.remove(&position),
+        };
+
+        if removed.is_some() {
+            self.len -= 1;
+            // Only a position on the edge can shrink the bounding box
+            if self.bounds.is_some_and(|bounds| bounds.on_edge(position)) {
+                self.bounds = self.occupied_bounds();
+            }
+        }
+        removed
+    }
+
+    fn occupied_bounds(&self) -> Option<Bounds> {
+        let mut positions = self.iter().map(|(position, _)| position);
+        let mut bounds = Bounds::of(positions.next()?);
+        positions.for_each(|position| bounds.extend(position));
+        Some(bounds)
+    }
+
+    /// Occupied positions with their values, row by row for a dense grid and
+    /// in no particular order for a sparse one.
+    pub fn iter(&self) -> Box<dyn Iterator<Item = (Position, &T)> + '_> {
+        match &self.cells {
+            Cells::Dense { width, cells, .. } => {
+                let width = *width;
+                Box::new(cells.iter().enumerate().filter_map(move |(i, cell)| {
+                    cell.as_ref().map(|value| (Point2::new((i % width) as i32, (i / width) as i32), value))
+                }))
+            }
+            Cells::Sparse(cells) => Box::new(cells.iter().map(|(position, value)| (*position, value))),
+        }
+    }
+
+    /// Position of the first value matching `predicate`, in [`Grid::iter`] order.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter().find(|(_, value)| predicate(value)).map(|(position, _)| position)
+    }
+
+    /// Orthogonal neighbours of `position` the grid can hold.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        position.neighbours4().into_iter().filter(|&neighbour| self.is_inside(neighbour))
+    }
+
+    /// Orthogonal and diagonal neighbours of `position` the grid can hold.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        position.neighbours8().into_iter().filter(|&neighbour| self.is_inside(neighbour))
+    }
+
+    /// Occupied positions of row `y` from left to right, reversible to walk
+    /// it from the right.
+    pub fn row(&self, y: i32) -> impl DoubleEndedIterator<Item = (Position, &T)> + '_ {
+        let (min, max) = self.extent().map_or((0, -1), |extent| (extent.min.x, extent.max.x));
+        (min..=max).map(move |x| Point2::new(x, y)).filter_map(|position| self.get(position).map(|value| (position, value)))
+    }
+
+    /// Occupied positions of column `x` from top to bottom, reversible to
+    /// walk it from the bottom.
+    pub fn column(&self, x: i32) -> impl DoubleEndedIterator<Item = (Position, &T)> + '_ {
+        let (min, max) = self.extent().map_or((0, -1), |extent| (extent.min.y, extent.max.y));
+        (min..=max).map(move |y| Point2::new(x, y)).filter_map(|position| self.get(position).map(|value| (position, value)))
+    }
+
+    /// Draws the grid's extent one character per position, each row ended by
+    /// a newline.
+    pub fn render(&self, mut tile: impl FnMut(Position, Option<&T>) -> char) -> String {
+        match self.extent() {
+            Some(extent) => extent.render(|position| tile(position, self.get(position))),
+            None => String::new(),
+        }
+    }
+}
+
+/// Value at an occupied position; panics on an empty one.
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position).unwrap_or_else(|| panic!("No value at {:?}", position))
+    }
+}
+
+fn index(width: usize, Point2 { x, y }: Position) -> usize {
+    y as usize * width + x as usize
+}
@@ -0,0 +1,87 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, ErrorKind, Read};
+use std::path::{Path, PathBuf};
+
+use crate::Result;
+
+/// Input file every day reads when no other path is given.
+pub const DEFAULT_INPUT: &str = "input.txt";
+
+/// Input path that reads standard input instead of a file.
+pub const STDIN: &str = "-";
+
+/// Reads the whole puzzle input into memory, from stdin when `path` is `-`.
+pub fn read_input(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+    let mut input = String::new();
+
+    let read = if is_stdin(path) {
+        io::stdin().read_to_string(&mut input).map(|_| ())
+    } else {
+        fs::read_to_string(path).map(|text| input = text)
+    };
+    read.map_err(|source| InputError { path: path.to_path_buf(), source })?;
+
+    Ok(input)
+}
+
+/// Input files behind `path`: every file of a directory, sorted by name and
+/// skipping hidden ones, or `path` itself for a file or `-`.
+pub fn input_files(path: impl AsRef<Path>) -> Result<Vec<PathBuf>> {
+    let path = path.as_ref();
+    if is_stdin(path) || !path.is_dir() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+
+    let entries = fs::read_dir(path).map_err(|source| InputError { path: path.to_path_buf(), source })?;
+    let mut files = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+        if !hidden && entry.file_type()?.is_file() {
+            files.push(entry.path());
+        }
+    }
+    files.sort();
+
+    if files.is_empty() {
+        return Err(Box::new(InputError { path: path.to_path_buf(), source: io::Error::new(ErrorKind::NotFound, "no input files in directory") }));
+    }
+
+    Ok(files)
+}
+
+pub fn is_stdin(path: &Path) -> bool {
+    path == Path::new(STDIN)
+}
+
+/// Name to show for an input path, `<stdin>` for `-`.
+pub fn display_name(path: &Path) -> String {
+    if is_stdin(path) {
+        return String::from("<stdin>");
+    }
+    path.display().to_string()
+}
+
+/// An input that could not be read, naming the path that was tried.
+#[derive(Debug)]
+pub struct InputError {
+    path: PathBuf,
+    source: io::Error,
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.source.kind() {
+            ErrorKind::NotFound if !self.path.exists() => write!(f, "{} does not exist", display_name(&self.path)),
+            _ => write!(f, "Cannot read {}: {}", display_name(&self.path), self.source),
+        }
+    }
+}
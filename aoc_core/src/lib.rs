@@ -0,0 +1,35 @@
+//! Shared plumbing for the Advent of Code 2022 day crates: the `Puzzle` trait
+//! every day implements, the common error type, input loading, answers, part
+//! selection, per-day settings, parse errors, 2D grids, geometry, graph
+//! searches, output formats, logging, benchmarking, random input generation,
+//! simulation playback, JSON output and the example inputs used by the
+//! regression tests.
+
+pub mod answer;
+pub mod bench;
+pub mod cli;
+pub mod config;
+pub mod example;
+pub mod format;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod log;
+pub mod parse;
+pub mod part;
+pub mod puzzle;
+pub mod search;
+pub mod visualize;
+
+pub use answer::Answer;
+pub use config::{Config, DayConfig};
+pub use format::Format;
+pub use input::read_input;
+pub use parse::ParseError;
+pub use part::{Part, Unsolved};
+pub use puzzle::Puzzle;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = std::result::Result<T, Error>;
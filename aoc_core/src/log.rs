@@ -0,0 +1,141 @@
+//! Diagnostics for the solvers, written to stderr so stdout only carries
+//! answers. Messages are filtered by a process-wide [`Level`] chosen on the
+//! command line:
+//!
+//! ```text
+//! -q, --quiet     answers only, no status lines
+//!                 (normal) answers and status lines
+//! -v, --verbose   plus per-part summaries, e.g. day 11 inspection counts
+//! -vv, --trace    plus per-step dumps, e.g. day 9 tail positions
+//! ```
+//!
+//! Days log with the [`info!`](crate::info), [`verbose!`](crate::verbose) and
+//! [`trace!`](crate::trace) macros; anything expensive to format is guarded
+//! with [`enabled`].
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::Result;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Level {
+    Quiet,
+    #[default]
+    Normal,
+    Verbose,
+    Trace,
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Normal as u8);
+
+impl Level {
+    pub const ALL: [Level; 4] = [Level::Quiet, Level::Normal, Level::Verbose, Level::Trace];
+
+    /// Level requested on the command line, normal when no flag is given.
+    pub fn selected() -> Result<Level> {
+        Level::from_args(std::env::args().skip(1))
+    }
+
+    /// Picks the level from `-q`/`--quiet`, `-v`/`--verbose`, `-vv`/`--trace`
+    /// or `--log <LEVEL>`; the last one given wins.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Level> {
+        let mut level = Level::Normal;
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            level = match arg.as_str() {
+                "--log" => args.next().ok_or(LevelError(String::new()))?.parse()?,
+                other => Level::from_flag(other).unwrap_or(level),
+            };
+        }
+
+        Ok(level)
+    }
+
+    /// Level for a shorthand flag such as `-v`, `None` for any other argument.
+    pub fn from_flag(flag: &str) -> Option<Level> {
+        match flag {
+            "-q" | "--quiet" => Some(Level::Quiet),
+            "-v" | "--verbose" => Some(Level::Verbose),
+            "-vv" | "--trace" => Some(Level::Trace),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Quiet => write!(f, "quiet"),
+            Level::Normal => write!(f, "normal"),
+            Level::Verbose => write!(f, "verbose"),
+            Level::Trace => write!(f, "trace"),
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = LevelError;
+
+    fn from_str(s: &str) -> std::result::Result<Level, LevelError> {
+        Level::ALL.into_iter()
+            .find(|level| level.to_string() == s.trim())
+            .ok_or_else(|| LevelError(s.to_string()))
+    }
+}
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    Level::ALL[LEVEL.load(Ordering::Relaxed) as usize]
+}
+
+/// Whether messages at `level` are currently printed.
+pub fn enabled(level: Level) -> bool {
+    level != Level::Quiet && level <= self::level()
+}
+
+/// Status line printed unless `--quiet` is given.
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Normal) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Summary of how a part reached its answer, printed with `--verbose`.
+#[macro_export]
+macro_rules! verbose {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Verbose) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Step-by-step dumps, printed with `--trace`.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[derive(Debug)]
+pub struct LevelError(String);
+
+impl std::error::Error for LevelError {}
+
+impl fmt::Display for LevelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid log level '{}', possible values 'quiet', 'normal', 'verbose', 'trace'", self.0)
+    }
+}
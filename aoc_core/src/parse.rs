@@ -0,0 +1,127 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::{input, Error};
+
+/// Malformed input: where it went wrong, what was there and what the parser
+/// wanted instead. Lines and columns are 1-based, columns count characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub found: String,
+    pub expected: String,
+    pub source_line: String,
+    /// Input file, when known; filled in by [`in_file`].
+    pub path: Option<PathBuf>,
+}
+
+impl ParseError {
+    /// Error on `found`, which should be a slice of `line` so the column can
+    /// be worked out from it. Any other text is searched for in the line and
+    /// points at the line start when it is not there.
+    pub fn new(line_number: usize, line: &str, found: &str, expected: impl Into<String>) -> ParseError {
+        let line_start = line.as_ptr() as usize;
+        let found_start = found.as_ptr() as usize;
+
+        let offset = if found_start >= line_start && found_start + found.len() <= line_start + line.len() {
+            found_start - line_start
+        } else {
+            line.find(found).unwrap_or(0)
+        };
+
+        ParseError {
+            line: line_number,
+            column: line[..offset].chars().count() + 1,
+            found: found.to_string(),
+            expected: expected.into(),
+            source_line: line.to_string(),
+            path: None,
+        }
+    }
+
+    /// Error on the single character starting at byte `index` of `line`.
+    pub fn at_char(line_number: usize, line: &str, index: usize, expected: impl Into<String>) -> ParseError {
+        let len = line[index..].chars().next().map_or(0, char::len_utf8);
+        ParseError::new(line_number, line, &line[index..index + len], expected)
+    }
+
+    /// The line stopped before the parser got what it expected.
+    pub fn end_of_line(line_number: usize, line: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::new(line_number, line, &line[line.len()..], expected)
+    }
+
+    /// The input stopped before the parser got what it expected.
+    pub fn end_of_input(input: &str, expected: impl Into<String>) -> ParseError {
+        let (line_number, line) = numbered_lines(input).last().unwrap_or((1, ""));
+        ParseError::end_of_line(line_number, line, expected)
+    }
+
+    /// Compiler-style snippet with a caret under the offending text:
+    ///
+    /// ```text
+    /// error: expected a number, found '12x'
+    ///  --> input.txt:3:1
+    ///   |
+    /// 3 | 12x
+    ///   | ^^^
+    /// ```
+    pub fn render(&self) -> String {
+        let location = match &self.path {
+            Some(path) => format!("{}:{}:{}", input::display_name(path), self.line, self.column),
+            None => format!("line {}, column {}", self.line, self.column),
+        };
+        let gutter = " ".repeat(self.line.to_string().len());
+        let carets = "^".repeat(self.found.chars().count().max(1));
+        let padding = " ".repeat(self.column - 1);
+
+        format!("error: {}\n{}--> {}\n{} |\n{} | {}\n{} | {}{}",
+            self.message(), gutter, location, gutter, self.line, self.source_line, gutter, padding, carets)
+    }
+
+    fn message(&self) -> String {
+        match self.found.as_str() {
+            "" => format!("expected {}, found end of line", self.expected),
+            found => format!("expected {}, found '{}'", self.expected, found),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message())
+    }
+}
+
+/// Lines of the input paired with their 1-based line numbers.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.lines().enumerate().map(|(i, line)| (i + 1, line))
+}
+
+/// Parses `token`, a slice of `line`, as a number.
+pub fn number<T: FromStr>(line_number: usize, line: &str, token: &str) -> Result<T, ParseError> {
+    token.parse().map_err(|_| ParseError::new(line_number, line, token, "a number"))
+}
+
+/// Attaches the input file to a parse error; other errors pass through.
+pub fn in_file(error: Error, path: &Path) -> Error {
+    match error.downcast::<ParseError>() {
+        Ok(mut e) => {
+            e.path = Some(path.to_path_buf());
+            e
+        }
+        Err(e) => e,
+    }
+}
+
+/// Message for a failed run: parse errors get the full snippet, anything
+/// else a single line.
+pub fn report(error: &Error) -> String {
+    match error.downcast_ref::<ParseError>() {
+        Some(e) => e.render(),
+        None => format!("error: {}", error),
+    }
+}
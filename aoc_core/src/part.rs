@@ -0,0 +1,80 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Result;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    /// Parts requested on the command line with `--part <1|2>`, both parts
+    /// when the flag is missing.
+    pub fn selected() -> Result<Vec<Part>> {
+        Part::from_args(std::env::args().skip(1))
+    }
+
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Vec<Part>> {
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if arg == "--part" || arg == "-p" {
+                let value = args.next().ok_or(PartError(String::new()))?;
+                return Ok(vec![value.parse()?]);
+            }
+        }
+
+        Ok(Part::ALL.to_vec())
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = PartError;
+
+    fn from_str(s: &str) -> std::result::Result<Part, PartError> {
+        match s.trim() {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            other => Err(PartError(other.to_string())),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PartError(String);
+
+impl std::error::Error for PartError {}
+
+impl fmt::Display for PartError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid part '{}', possible values '1', '2'", self.0)
+    }
+}
+
+/// Returned by a part that has no solution yet.
+#[derive(Debug)]
+pub struct Unsolved(pub Part);
+
+impl std::error::Error for Unsolved {}
+
+impl fmt::Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Part {} is not solved yet", self.0)
+    }
+}
@@ -0,0 +1,131 @@
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use crate::bench::{self, Bench};
+use crate::generate::{self, Generate};
+use crate::{Answer, Config, DayConfig, Part, Result};
+
+/// A single day's puzzle. The input is parsed once and both parts are solved
+/// from the parsed form.
+pub trait Puzzle {
+    /// Day number, which also names the day's `[dayN]` config table.
+    const DAY: u8;
+
+    type Parsed;
+    type Answer1: Into<Answer>;
+    type Answer2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Parsed>;
+    fn part1(parsed: &Self::Parsed) -> Result<Self::Answer1>;
+    fn part2(parsed: &Self::Parsed) -> Result<Self::Answer2>;
+
+    /// Applies the day's settings to the parsed input. Days with tunable
+    /// parameters keep them in `Parsed` with their defaults from `parse`.
+    fn configure(_parsed: &mut Self::Parsed, _config: &DayConfig) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Parses the input and applies the day's settings from `config`.
+pub fn parse_configured<P: Puzzle>(input: &str, config: &Config) -> Result<P::Parsed> {
+    let mut parsed = P::parse(input)?;
+
+    let day_config = config.day(P::DAY);
+    P::configure(&mut parsed, &day_config)?;
+    day_config.check_unused()?;
+
+    Ok(parsed)
+}
+
+/// Solves one part of an already parsed puzzle.
+pub fn solve_parsed<P: Puzzle>(parsed: &P::Parsed, part: Part) -> Result<Answer> {
+    match part {
+        Part::One => Ok(P::part1(parsed)?.into()),
+        Part::Two => Ok(P::part2(parsed)?.into()),
+    }
+}
+
+/// Parses the input and solves one part with the given settings.
+pub fn solve<P: Puzzle>(input: &str, part: Part, config: &Config) -> Result<Answer> {
+    let parsed = parse_configured::<P>(input, config)?;
+    solve_parsed::<P>(&parsed, part)
+}
+
+/// Answers and timings of solving the requested parts of one input.
+pub struct Run {
+    pub parse_elapsed: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Result<Answer>,
+    pub elapsed: Duration,
+}
+
+/// Parses the input once and solves every requested part, timing each phase.
+/// Fails only when the input does not parse; part errors are kept per part.
+pub fn run<P: Puzzle>(input: &str, parts: &[Part], config: &Config) -> Result<Run> {
+    let start = Instant::now();
+    let parsed = parse_configured::<P>(input, config)?;
+    let parse_elapsed = start.elapsed();
+
+    let parts = parts.iter().map(|&part| {
+        let start = Instant::now();
+        let answer = solve_parsed::<P>(&parsed, part);
+        PartRun { part, answer, elapsed: start.elapsed() }
+    }).collect();
+
+    Ok(Run { parse_elapsed, parts })
+}
+
+/// Runs `f`, turning a panic into its message instead of unwinding further.
+/// The default hook is silenced meanwhile, so the message is only reported
+/// where the caller puts it, like the batch table or a server response.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> std::result::Result<T, String> {
+    thread_local! {
+        static MESSAGE: RefCell<Option<String>> = const { RefCell::new(None) };
+    }
+
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(|info| MESSAGE.with(|message| *message.borrow_mut() = Some(info.to_string()))));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    panic::set_hook(previous);
+
+    result.map_err(|_| {
+        let message = MESSAGE.with(|message| message.borrow_mut().take());
+        message.unwrap_or_else(|| String::from("panicked")).replace('\n', " ")
+    })
+}
+
+/// Object-safe view of a `Puzzle`, so days with different parsed and answer
+/// types can sit in one table.
+pub trait Solver: Sync {
+    fn run(&self, input: &str, parts: &[Part], config: &Config) -> Result<Run>;
+    fn bench(&self, input: &str, parts: &[Part], config: &Config, iterations: usize) -> Result<Bench>;
+    /// Random input for `seed`, at `size` or the day's default size.
+    fn generate(&self, seed: u64, size: Option<usize>) -> String;
+}
+
+/// The `Solver` for puzzle `P`, e.g. `&Solve::<CalorieCounting>::NEW`.
+pub struct Solve<P>(PhantomData<fn() -> P>);
+
+impl<P> Solve<P> {
+    pub const NEW: Solve<P> = Solve(PhantomData);
+}
+
+impl<P: Generate> Solver for Solve<P> {
+    fn run(&self, input: &str, parts: &[Part], config: &Config) -> Result<Run> {
+        run::<P>(input, parts, config)
+    }
+
+    fn bench(&self, input: &str, parts: &[Part], config: &Config, iterations: usize) -> Result<Bench> {
+        bench::bench::<P>(input, parts, config, iterations)
+    }
+
+    fn generate(&self, seed: u64, size: Option<usize>) -> String {
+        generate::generate::<P>(seed, size)
+    }
+}
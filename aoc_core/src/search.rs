@@ -0,0 +1,241 @@
+//! Graph searches shared by the days looking for shortest routes: breadth
+//! first search, Dijkstra, A* and flood fill.
+//!
+//! Every search is generic over the node type and takes the graph as a
+//! function listing the neighbours of a node (with the cost of the step for
+//! the weighted searches), so grids, valve tunnels and states changing over
+//! time are searched the same way. Searches start from any number of nodes at
+//! once and remember where each node was reached from, so [`Paths::path`] can
+//! rebuild the route to it.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Cost of a step in a weighted search, starting from the default value.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<T: Copy + Ord + Default + Add<Output = T>> Cost for T {}
+
+/// Nodes reached by a search with their cost from the nearest start.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C = usize> {
+    costs: HashMap<N, C>,
+    parents: HashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Paths<N, C> {
+    fn new() -> Paths<N, C> {
+        Paths { costs: HashMap::new(), parents: HashMap::new(), goal: None }
+    }
+
+    /// Cost of the cheapest route to `node`, `None` when it was not reached.
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.costs.contains_key(node)
+    }
+
+    /// Goal the search stopped at, if it was given one and reached it.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    /// Cost of the route to the goal the search stopped at.
+    pub fn goal_cost(&self) -> Option<C> {
+        self.goal.as_ref().and_then(|goal| self.cost(goal))
+    }
+
+    /// Cheapest route from a start to `node`, both included.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        if !self.contains(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every reached node with its cost, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&N, &C)> {
+        self.costs.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.costs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.costs.is_empty()
+    }
+}
+
+/// Breadth first search from every start, reaching every node it can.
+/// The cost of a node is its number of steps from the nearest start.
+pub fn bfs<N, I>(starts: impl IntoIterator<Item = N>, neighbours: impl FnMut(&N) -> I) -> Paths<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    bfs_until(starts, neighbours, |_| false)
+}
+
+/// Breadth first search stopping at the first node `is_goal` accepts, which
+/// is then the nearest goal to any start.
+pub fn bfs_until<N, I>(starts: impl IntoIterator<Item = N>, mut neighbours: impl FnMut(&N) -> I, mut is_goal: impl FnMut(&N) -> bool) -> Paths<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !paths.contains(&start) {
+            paths.costs.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            paths.goal = Some(node);
+            break;
+        }
+
+        let cost = paths.costs[&node] + 1;
+        for next in neighbours(&node) {
+            if !paths.contains(&next) {
+                paths.costs.insert(next.clone(), cost);
+                paths.parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    paths
+}
+
+/// Every node reachable from the starts, the starts included.
+pub fn flood_fill<N, I>(starts: impl IntoIterator<Item = N>, mut neighbours: impl FnMut(&N) -> I) -> HashSet<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut stack = Vec::new();
+    for start in starts {
+        if seen.insert(start.clone()) {
+            stack.push(start);
+        }
+    }
+
+    while let Some(node) = stack.pop() {
+        for next in neighbours(&node) {
+            if seen.insert(next.clone()) {
+                stack.push(next);
+            }
+        }
+    }
+
+    seen
+}
+
+/// Dijkstra's search through steps of varying cost, stopping at the first
+/// node `is_goal` accepts (`|_| false` reaches everything).
+pub fn dijkstra<N, C, I>(starts: impl IntoIterator<Item = N>, neighbours: impl FnMut(&N) -> I, is_goal: impl FnMut(&N) -> bool) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbours, |_| C::default(), is_goal)
+}
+
+/// A* search: Dijkstra's search guided towards the goals by `heuristic`, an
+/// estimate of the remaining cost. Nodes are closed once expanded and never
+/// reopened, so for the route found to be the cheapest the heuristic must be
+/// consistent: never more than a step's cost plus the estimate after it, and
+/// zero at the goals (like the Manhattan distance on a grid of unit steps).
+/// Merely never overestimating is not enough.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        let estimate = heuristic(&start);
+        paths.costs.insert(start.clone(), C::default());
+        queue.push(Queued { estimate, cost: C::default(), node: start });
+    }
+
+    let mut done = HashSet::new();
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        // Nodes are queued again when a cheaper route is found; with a
+        // consistent heuristic the first time one comes out is the cheapest
+        if !done.insert(node.clone()) {
+            continue;
+        }
+        if is_goal(&node) {
+            paths.goal = Some(node);
+            break;
+        }
+
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            if paths.cost(&next).is_some_and(|known| known <= next_cost) {
+                continue;
+            }
+
+            paths.costs.insert(next.clone(), next_cost);
+            paths.parents.insert(next.clone(), node.clone());
+            let estimate = next_cost + heuristic(&next);
+            queue.push(Queued { estimate, cost: next_cost, node: next });
+        }
+    }
+
+    paths
+}
+
+/// Node waiting in the A* queue, ordered so the lowest estimate comes out
+/// of the max-heap first.
+struct Queued<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
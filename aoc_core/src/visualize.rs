@@ -0,0 +1,134 @@
+//! Step by step ASCII pictures of the simulation days.
+//!
+//! Days whose answer comes out of a simulation (the rope, the falling sand
+//! and rocks, the spreading elves, the blizzards) implement [`Visualize`],
+//! drawing a [`Frame`] for every step into [`Frames`]. The frames are played
+//! back in the terminal by `aoc visualize` or exported as plain text or as an
+//! [asciicast](https://docs.asciinema.org/manual/asciicast/v2/) recording.
+
+use crate::json::Json;
+use crate::puzzle::parse_configured;
+use crate::{Config, Puzzle, Result};
+
+/// Frames kept when the caller does not pick a limit.
+pub const DEFAULT_LIMIT: usize = 10_000;
+
+/// Moves the cursor home and clears the terminal.
+pub const CLEAR_SCREEN: &str = "\x1b[H\x1b[2J";
+
+/// One step of a simulation: a caption and the picture below it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub caption: String,
+    /// Lines of the picture, each ended by a newline.
+    pub picture: String,
+}
+
+impl Frame {
+    pub fn width(&self) -> usize {
+        self.lines().map(|line| line.chars().count()).max().unwrap_or(0)
+    }
+
+    /// Lines taken on screen, the caption included.
+    pub fn height(&self) -> usize {
+        self.lines().count()
+    }
+
+    fn lines(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.caption.as_str()).chain(self.picture.lines())
+    }
+}
+
+/// Frames drawn so far, up to a limit after which the simulation stops.
+#[derive(Debug)]
+pub struct Frames {
+    frames: Vec<Frame>,
+    limit: usize,
+}
+
+impl Frames {
+    pub fn new(limit: usize) -> Frames {
+        Frames { frames: Vec::new(), limit }
+    }
+
+    /// Adds the next frame. Returns whether more are wanted, a simulation
+    /// should stop as soon as this is false.
+    pub fn push(&mut self, caption: impl Into<String>, picture: String) -> bool {
+        if !self.is_full() {
+            self.frames.push(Frame { caption: caption.into(), picture });
+        }
+        !self.is_full()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.frames.len() >= self.limit
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub fn into_vec(self) -> Vec<Frame> {
+        self.frames
+    }
+}
+
+/// A puzzle whose simulation can be watched step by step.
+pub trait Visualize: Puzzle {
+    /// Runs the simulation on `parsed`, pushing a frame for the starting
+    /// state and for every step until it ends or `frames` is full.
+    fn visualize(parsed: &Self::Parsed, frames: &mut Frames) -> Result<()>;
+}
+
+/// Frames of `P` simulating `input` with the day's settings from `config`,
+/// at most `limit` of them.
+pub fn frames<P: Visualize>(input: &str, config: &Config, limit: usize) -> Result<Vec<Frame>> {
+    let parsed = parse_configured::<P>(input, config)?;
+
+    let mut frames = Frames::new(limit);
+    P::visualize(&parsed, &mut frames)?;
+    Ok(frames.into_vec())
+}
+
+/// Object-safe entry point of a day's [`frames`], e.g.
+/// `frames::<RopeBridge>` as a `Visualizer`.
+pub type Visualizer = fn(&str, &Config, usize) -> Result<Vec<Frame>>;
+
+/// Plain text export: every caption on its own line followed by the picture
+/// and a blank line.
+pub fn to_text(frames: &[Frame]) -> String {
+    let mut text = String::new();
+    for frame in frames {
+        text.push_str(&frame.caption);
+        text.push('\n');
+        text.push_str(&frame.picture);
+        text.push('\n');
+    }
+    text
+}
+
+/// Asciicast v2 recording showing `fps` frames per second: a header line
+/// sized to the largest frame, then one event clearing the screen and
+/// drawing each frame.
+pub fn to_asciicast(frames: &[Frame], fps: usize, title: &str) -> String {
+    let width = frames.iter().map(Frame::width).max().unwrap_or(0);
+    let height = frames.iter().map(Frame::height).max().unwrap_or(0);
+    let header = Json::object()
+        .field("version", 2)
+        .field("width", width.max(1))
+        .field("height", height.max(1))
+        .field("title", title);
+
+    let mut cast = format!("{}\n", header);
+    for (i, frame) in frames.iter().enumerate() {
+        let time = i as f64 / fps.max(1) as f64;
+        let screen = format!("{}{}", CLEAR_SCREEN, frame.lines().collect::<Vec<_>>().join("\r\n"));
+        let event = Json::Array(vec![Json::from(time), Json::from("o"), Json::from(screen)]);
+        cast.push_str(&format!("{}\n", event));
+    }
+    cast
+}